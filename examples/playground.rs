@@ -1,5 +1,5 @@
 #[cfg(feature = "simulate-example")]
-use std::{thread, time::Duration};
+use std::{cell::Cell, thread, time::Duration};
 
 #[cfg(feature = "simulate-example")]
 use chrono::Local;
@@ -8,7 +8,7 @@ use embedded_graphics::{
     geometry::Dimensions,
     mono_font::{iso_8859_1::FONT_6X9, MonoTextStyle},
     pixelcolor::BinaryColor,
-    prelude::{DrawTarget, Point, Size},
+    prelude::{Point, Size},
     text::Text,
 };
 #[cfg(feature = "simulate-example")]
@@ -20,8 +20,9 @@ use env_logger::Env;
 
 #[cfg(feature = "simulate-example")]
 use simple_layout::prelude::{
-    bordered, center, expand, horizontal_layout, optional_placement, padding, scale, south,
-    vertical_layout, DashedLine, Layoutable, RoundedLine,
+    bordered, center, dispatch, expand, horizontal_layout, redraw_dirty, scale, south, text_button,
+    tracked, vertical_layout, ButtonState, ButtonStyle, DashedLine, DirtyState, InputEvent,
+    RoundedLine,
 };
 
 #[cfg(not(feature = "simulate-example"))]
@@ -42,17 +43,29 @@ fn main() -> Result<(), core::convert::Infallible> {
         .theme(BinaryColorTheme::LcdWhite)
         .build();
     let mut window = Window::new("Display Test", &output_settings);
-    let mut modified = true;
-    let mut minus_pos = None;
-    let mut plus_pos = None;
-    let mut scale_value = 0.5;
+    let modified = Cell::new(true);
+    let scale_value = Cell::new(0.5);
+    let mut screen_state = DirtyState::default();
+    let mut scale_state = DirtyState::default();
     let mut display = SimulatorDisplay::<BinaryColor>::new(Size::new(64, 128));
     let rectangle = display.bounding_box();
+    let minus_state = ButtonState::default();
+    let plus_state = ButtonState::default();
+    let button_style =
+        ButtonStyle::new(BinaryColor::On, BinaryColor::Off).with_min_size(Size::new(16, 16));
+    let step = |step: f32| {
+        let (scale_value, modified) = (&scale_value, &modified);
+        move || {
+            scale_value.set(scale_value.get() + step);
+            modified.set(true);
+        }
+    };
 
     'running: loop {
-        if modified {
-            display.clear(BinaryColor::Off)?;
-            let pressure_string = "Footer\nXYq";
+        let pressure_string = "Footer\nXYq";
+        let screen = tracked(
+            &mut screen_state,
+            (),
             vertical_layout(
                 expand(bordered(
                     center(clock_text),
@@ -69,53 +82,43 @@ fn main() -> Result<(), core::convert::Infallible> {
             )
             .append(
                 horizontal_layout(
-                    optional_placement(
-                        &mut minus_pos,
-                        bordered(
-                            padding(Text::new("-", Point::zero(), text_style), -1, 0, -1, 0),
-                            RoundedLine::new(BinaryColor::On),
-                        ),
-                    ),
+                    text_button(&minus_state, button_style, step(-0.2), "-", text_style),
                     0,
                 )
-                .append(south(scale(scale_value, BinaryColor::On)), 1)
                 .append(
-                    optional_placement(
-                        &mut plus_pos,
-                        bordered(
-                            padding(Text::new("+", Point::zero(), text_style), -1, 0, -1, 0),
-                            RoundedLine::new(BinaryColor::On),
-                        ),
+                    tracked(
+                        &mut scale_state,
+                        scale_value.get().to_bits(),
+                        south(scale(scale_value.get(), BinaryColor::On)),
                     ),
+                    1,
+                )
+                .append(
+                    text_button(&plus_state, button_style, step(0.2), "+", text_style),
                     0,
                 ),
                 0,
-            )
-            .draw_placed(&mut display, rectangle)?;
+            ),
+        );
+        if modified.replace(false) {
+            // only the changed scale is cleared and redrawn after the first frame
+            redraw_dirty(&screen, &mut display, rectangle, BinaryColor::Off)?;
             window.update(&display);
         }
-        modified = false;
 
         for event in window.events() {
-            match event {
-                SimulatorEvent::KeyUp { .. } => {}
-                SimulatorEvent::KeyDown { .. } => {}
-                SimulatorEvent::MouseButtonUp { point, .. } => {
-                    if plus_pos.map(|p| p.contains(point)).unwrap_or(false) {
-                        scale_value += 0.2;
-                        modified = true;
-                    }
-                    if minus_pos.map(|p| p.contains(point)).unwrap_or(false) {
-                        scale_value -= 0.2;
-                        modified = true;
-                    }
-                }
-                SimulatorEvent::MouseButtonDown { .. } => {}
-                SimulatorEvent::MouseWheel { .. } => {}
-                SimulatorEvent::MouseMove { .. } => {}
+            let event = match event {
+                SimulatorEvent::MouseButtonDown { point, .. } => InputEvent::Press(point),
+                SimulatorEvent::MouseButtonUp { point, .. } => InputEvent::Release(point),
+                SimulatorEvent::MouseMove { point } => InputEvent::Move(point),
                 SimulatorEvent::Quit => {
                     break 'running;
                 }
+                _ => continue,
+            };
+            if dispatch(&screen, rectangle, event) {
+                // pressed buttons change their look
+                modified.set(true);
             }
         }
 
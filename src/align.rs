@@ -6,6 +6,9 @@ use embedded_graphics::{
     primitives::Rectangle,
 };
 
+use crate::dirty::DirtyRegion;
+use crate::event::InputEvent;
+use crate::inspect::LayoutNode;
 use crate::layoutable::Layoutable;
 use crate::{ComponentSize, ValueRange};
 
@@ -57,6 +60,34 @@ pub fn south<L: Layoutable<C>, C: PixelColor>(l: L) -> impl Layoutable<C> {
     AlignLayout::<_, _, CenteredAlignment, EndAlignment>::new(l)
 }
 
+///
+/// Placement of a element within a larger space along one axis
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum Align {
+    /// at the left or top of the available space
+    Start,
+    /// in the middle of the available space
+    #[default]
+    Center,
+    /// at the right or bottom of the available space
+    End,
+}
+
+impl Align {
+    pub(crate) fn place(
+        self,
+        available_range: Saturating<u32>,
+        target_range: ValueRange<Saturating<u32>>,
+    ) -> (Saturating<i32>, Saturating<u32>) {
+        match self {
+            Align::Start => StartAlignment::place(available_range, target_range),
+            Align::Center => CenteredAlignment::place(available_range, target_range),
+            Align::End => EndAlignment::place(available_range, target_range),
+        }
+    }
+}
+
 trait Alignment {
     fn place(
         available_range: Saturating<u32>,
@@ -113,6 +144,23 @@ impl<L: Layoutable<C>, C: PixelColor, HA: Alignment, VA: Alignment> Layoutable<C
         self.layoutable
             .draw_placed(target, Self::place(self.layoutable.size(), position))
     }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(LayoutNode::new(
+            &self.layoutable,
+            Self::place(self.layoutable.size(), position),
+        ));
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        self.layoutable
+            .collect_dirty(Self::place(self.layoutable.size(), position), dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.layoutable
+            .handle_event(Self::place(self.layoutable.size(), position), event)
+    }
 }
 
 pub struct CenteredAlignment;
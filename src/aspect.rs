@@ -0,0 +1,185 @@
+use std::marker::PhantomData;
+use std::num::Saturating;
+
+use embedded_graphics::{
+    draw_target::DrawTarget, geometry::Point, pixelcolor::PixelColor, prelude::Size,
+    primitives::Rectangle,
+};
+
+use crate::align::Align;
+use crate::dirty::DirtyRegion;
+use crate::event::InputEvent;
+use crate::inspect::LayoutNode;
+use crate::layoutable::Layoutable;
+use crate::{ComponentSize, ValueRange};
+
+///
+/// Keep the ratio between width and height of a element, the element is centered within the available space
+///
+/// # Arguments
+///
+/// * `ratio`: width divided by height (invalid ratios like 0.0 or NaN are treated as 1.0)
+/// * `layoutable`: element to place
+///
+/// returns: impl Layoutable<C>+Sized
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{aspect_ratio, expand, inspect, scale};
+/// let square = aspect_ratio(1.0, expand(scale(0.3, BinaryColor::On)));
+/// let tree = inspect(&square, Rectangle::new(Point::zero(), Size::new(40, 20)));
+/// assert_eq!(
+///     tree.children()[0].position(),
+///     Rectangle::new(Point::new(10, 0), Size::new(20, 20))
+/// );
+/// ```
+pub fn aspect_ratio<L: Layoutable<C>, C: PixelColor>(
+    ratio: f32,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    aspect_ratio_aligned(ratio, Align::Center, Align::Center, layoutable)
+}
+
+///
+/// Keep the ratio between width and height of a element and place it within the available space
+///
+/// # Arguments
+///
+/// * `ratio`: width divided by height (invalid ratios like 0.0 or NaN are treated as 1.0)
+/// * `horizontal`: horizontal placement of the element
+/// * `vertical`: vertical placement of the element
+/// * `layoutable`: element to place
+///
+/// returns: impl Layoutable<C>+Sized
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{aspect_ratio_aligned, expand, inspect, scale, Align};
+/// let gauge = aspect_ratio_aligned(2.0, Align::End, Align::Center, expand(scale(0.3, BinaryColor::On)));
+/// let tree = inspect(&gauge, Rectangle::new(Point::zero(), Size::new(40, 10)));
+/// assert_eq!(
+///     tree.children()[0].position(),
+///     Rectangle::new(Point::new(20, 0), Size::new(20, 10))
+/// );
+/// ```
+pub fn aspect_ratio_aligned<L: Layoutable<C>, C: PixelColor>(
+    ratio: f32,
+    horizontal: Align,
+    vertical: Align,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    AspectRatio {
+        layoutable,
+        ratio: if ratio.is_finite() && ratio > 0.0 {
+            ratio
+        } else {
+            1.0
+        },
+        horizontal,
+        vertical,
+        p: PhantomData,
+    }
+}
+
+struct AspectRatio<L: Layoutable<C>, C: PixelColor> {
+    layoutable: L,
+    ratio: f32,
+    horizontal: Align,
+    vertical: Align,
+    p: PhantomData<C>,
+}
+
+impl<L: Layoutable<C>, C: PixelColor> AspectRatio<L, C> {
+    fn width_of(&self, height: u32) -> u32 {
+        (height as f32 * self.ratio).round() as u32
+    }
+    fn height_of(&self, width: u32) -> u32 {
+        (width as f32 / self.ratio).round() as u32
+    }
+    /// smallest ratio-correct size containing the given size
+    fn enclosing(&self, width: u32, height: u32) -> (u32, u32) {
+        (
+            width.max(self.width_of(height)),
+            height.max(self.height_of(width)),
+        )
+    }
+    fn place(&self, available_area: Rectangle) -> Rectangle {
+        let Size {
+            width: available_width,
+            height: available_height,
+        } = available_area.size;
+        let max_size = self.layoutable.size().max_size();
+        let mut width = available_width.min(self.width_of(available_height));
+        let mut height = available_height.min(self.height_of(available_width));
+        if width > max_size.width {
+            width = max_size.width;
+            height = self.height_of(width);
+        }
+        if height > max_size.height {
+            height = max_size.height;
+            width = self.width_of(height);
+        }
+        let (Saturating(x), Saturating(width)) = self.horizontal.place(
+            Saturating(available_width),
+            ValueRange::fixed(width.min(available_width)),
+        );
+        let (Saturating(y), Saturating(height)) = self.vertical.place(
+            Saturating(available_height),
+            ValueRange::fixed(height.min(available_height)),
+        );
+        Rectangle {
+            top_left: available_area.top_left + Point { x, y },
+            size: Size { width, height },
+        }
+    }
+}
+
+impl<L: Layoutable<C>, C: PixelColor> Layoutable<C> for AspectRatio<L, C> {
+    fn size(&self) -> ComponentSize {
+        let size = self.layoutable.size();
+        let (preferred_width, preferred_height) =
+            self.enclosing(size.width.preferred_value.0, size.height.preferred_value.0);
+        let (min_width, min_height) =
+            self.enclosing(size.width.min_value.0, size.height.min_value.0);
+        ComponentSize {
+            width: ValueRange {
+                preferred_value: Saturating(preferred_width),
+                min_value: Saturating(min_width),
+                max_value: size.width.max_value.max(Saturating(preferred_width)),
+            },
+            height: ValueRange {
+                preferred_value: Saturating(preferred_height),
+                min_value: Saturating(min_height),
+                max_value: size.height.max_value.max(Saturating(preferred_height)),
+            },
+        }
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        self.layoutable.draw_placed(target, self.place(position))
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(LayoutNode::new(&self.layoutable, self.place(position)));
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        self.layoutable.collect_dirty(self.place(position), dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.layoutable.handle_event(self.place(position), event)
+    }
+}
@@ -0,0 +1,258 @@
+use std::marker::PhantomData;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    pixelcolor::{BinaryColor, PixelColor, Rgb888, RgbColor},
+    primitives::{PointsIter, Rectangle},
+};
+
+use crate::{
+    dirty::DirtyRegion, event::InputEvent, inspect::LayoutNode, layoutable::Layoutable,
+    ComponentSize,
+};
+
+///
+/// Defines how a area gets filled
+///
+/// Every color is a paint filling the area with a solid color.
+///
+pub trait Paint<C: PixelColor> {
+    ///
+    /// Fill a area of the target
+    ///
+    /// # Arguments
+    ///
+    /// * `target`: Target to draw onto
+    /// * `area`: area to fill
+    ///
+    /// returns: Result<(), DrawError>
+    ///
+    fn fill<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        area: Rectangle,
+    ) -> Result<(), DrawError>;
+}
+
+impl<C: PixelColor> Paint<C> for C {
+    fn fill<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        area: Rectangle,
+    ) -> Result<(), DrawError> {
+        target.fill_solid(&area, *self)
+    }
+}
+
+const BAYER_MATRIX: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+///
+/// Ordered dither pattern to simulate gray levels on monochrome displays
+///
+/// The pattern is aligned to the display coordinates, so adjacent areas fit seamlessly together.
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DitherPattern {
+    level: u8,
+}
+
+impl DitherPattern {
+    ///
+    /// Create a pattern with a given density of enabled pixels
+    ///
+    /// # Arguments
+    ///
+    /// * `level`: count of enabled pixels of each 4x4 block (0 all off - 16 all on)
+    ///
+    /// returns: DitherPattern
+    ///
+    pub fn new(level: u8) -> Self {
+        Self {
+            level: level.min(16),
+        }
+    }
+    ///
+    /// Every second pixel is enabled
+    ///
+    pub fn checkerboard() -> Self {
+        Self::new(8)
+    }
+    fn color_at(&self, Point { x, y }: Point) -> BinaryColor {
+        (BAYER_MATRIX[y.rem_euclid(4) as usize][x.rem_euclid(4) as usize] < self.level).into()
+    }
+}
+
+impl Paint<BinaryColor> for DitherPattern {
+    fn fill<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = BinaryColor, Error = DrawError>,
+        area: Rectangle,
+    ) -> Result<(), DrawError> {
+        target.fill_contiguous(&area, area.points().map(|p| self.color_at(p)))
+    }
+}
+
+///
+/// Smooth transition between two colors across the filled area
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct LinearGradient<C: PixelColor> {
+    from: C,
+    to: C,
+    vertical: bool,
+}
+
+impl<C: PixelColor + From<Rgb888> + Into<Rgb888>> LinearGradient<C> {
+    ///
+    /// Transition from the left to the right side
+    ///
+    /// # Arguments
+    ///
+    /// * `from`: Color on the left side
+    /// * `to`: Color on the right side
+    ///
+    /// returns: LinearGradient<C>
+    ///
+    pub fn horizontal(from: C, to: C) -> Self {
+        Self {
+            from,
+            to,
+            vertical: false,
+        }
+    }
+    ///
+    /// Transition from the top to the bottom
+    ///
+    /// # Arguments
+    ///
+    /// * `from`: Color on the top
+    /// * `to`: Color on the bottom
+    ///
+    /// returns: LinearGradient<C>
+    ///
+    pub fn vertical(from: C, to: C) -> Self {
+        Self {
+            from,
+            to,
+            vertical: true,
+        }
+    }
+    fn color_at(&self, step: u32, steps: u32) -> C {
+        let from: Rgb888 = self.from.into();
+        let to: Rgb888 = self.to.into();
+        let mix = |a: u8, b: u8| {
+            (a as u32 * (steps - step) + b as u32 * step)
+                .checked_div(steps)
+                .map(|v| v as u8)
+                .unwrap_or(a)
+        };
+        Rgb888::new(
+            mix(from.r(), to.r()),
+            mix(from.g(), to.g()),
+            mix(from.b(), to.b()),
+        )
+        .into()
+    }
+}
+
+impl<C: PixelColor + From<Rgb888> + Into<Rgb888>> Paint<C> for LinearGradient<C> {
+    fn fill<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        area: Rectangle,
+    ) -> Result<(), DrawError> {
+        let Rectangle {
+            top_left: Point { x: sx, y: sy },
+            size: Size { width, height },
+        } = area;
+        target.fill_contiguous(
+            &area,
+            area.points().map(|Point { x, y }| {
+                if self.vertical {
+                    self.color_at((y - sy) as u32, height.saturating_sub(1))
+                } else {
+                    self.color_at((x - sx) as u32, width.saturating_sub(1))
+                }
+            }),
+        )
+    }
+}
+
+///
+/// Fill the area of a element before drawing the element
+///
+/// # Arguments
+///
+/// * `paint`: color or pattern to fill the area
+/// * `layoutable`: element to draw onto the filled area
+///
+/// returns: impl Layoutable<C>+Sized
+///
+/// # Examples
+///
+/// draw a inverted header on a monochrome display
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use simple_layout::prelude::{background, center, expand_horizontal, owned_text};
+/// let header = background(
+///     BinaryColor::On,
+///     expand_horizontal(center(owned_text("Settings", MonoTextStyle::new(&FONT_6X12, BinaryColor::Off)))),
+/// );
+/// ```
+/// highlight a row with a gray dither pattern
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use simple_layout::prelude::{background, owned_text, DitherPattern};
+/// let row = background(
+///     DitherPattern::new(4),
+///     owned_text("Selected", MonoTextStyle::new(&FONT_6X12, BinaryColor::On)),
+/// );
+/// ```
+pub fn background<L: Layoutable<C>, C: PixelColor, P: Paint<C>>(
+    paint: P,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    Background {
+        paint,
+        layoutable,
+        p: PhantomData,
+    }
+}
+
+struct Background<L: Layoutable<C>, C: PixelColor, P: Paint<C>> {
+    paint: P,
+    layoutable: L,
+    p: PhantomData<C>,
+}
+
+impl<L: Layoutable<C>, C: PixelColor, P: Paint<C>> Layoutable<C> for Background<L, C, P> {
+    fn size(&self) -> ComponentSize {
+        self.layoutable.size()
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        self.paint.fill(target, position)?;
+        self.layoutable.draw_placed(target, position)
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(LayoutNode::new(&self.layoutable, position));
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        self.layoutable.collect_dirty(position, dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.layoutable.handle_event(position, event)
+    }
+}
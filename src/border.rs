@@ -1,15 +1,46 @@
+use std::f32::consts::FRAC_1_SQRT_2;
 use std::marker::PhantomData;
-use std::num::Saturating;
 
 use embedded_graphics::{
-    draw_target::DrawTarget, geometry::Point, pixelcolor::PixelColor, prelude::Size,
-    primitives::Rectangle, Pixel,
+    draw_target::DrawTarget,
+    geometry::Point,
+    pixelcolor::PixelColor,
+    prelude::{Primitive, Size},
+    primitives::{
+        CornerRadii, PrimitiveStyleBuilder, Rectangle, RoundedRectangle, StrokeAlignment,
+    },
+    text::{renderer::TextRenderer, Baseline, Text, TextStyleBuilder},
+    Drawable, Pixel,
 };
 
-use crate::{layoutable::Layoutable, ComponentSize};
+use crate::{
+    background::Paint, dirty::DirtyRegion, event::InputEvent, inspect::LayoutNode,
+    layoutable::Layoutable, ComponentSize,
+};
 
+///
+/// Decoration drawn around a element by `bordered`
+///
 pub trait Decorator<C: PixelColor> {
-    fn width(&self) -> u32;
+    ///
+    /// Space reserved on each side of the element, use `Insets::uniform` for the same space on every side
+    ///
+    fn insets(&self) -> Insets;
+    ///
+    /// Area enclosed by the decoration which gets filled by `filled`, the area within the insets by default
+    ///
+    fn fill_area(&self, position: Rectangle) -> Rectangle {
+        self.insets().shrink(position)
+    }
+    ///
+    /// Smallest total size of the decorated element, independent of the size of the content
+    ///
+    fn minimum_size(&self) -> Size {
+        Size::zero()
+    }
+    ///
+    /// Draws the decoration onto the whole area of the element including the reserved space
+    ///
     fn draw_placed<DrawError>(
         &self,
         target: &mut impl DrawTarget<Color = C, Error = DrawError>,
@@ -17,6 +48,75 @@ pub trait Decorator<C: PixelColor> {
     ) -> Result<(), DrawError>;
 }
 
+///
+/// Space reserved by a decorator on each side of a element
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Insets {
+    pub top: u32,
+    pub right: u32,
+    pub bottom: u32,
+    pub left: u32,
+}
+
+impl Insets {
+    ///
+    /// Define the space on each side
+    ///
+    /// # Arguments
+    ///
+    /// * `top`: space above the element
+    /// * `right`: space right of the element
+    /// * `bottom`: space below the element
+    /// * `left`: space left of the element
+    ///
+    /// returns: Insets
+    ///
+    pub fn new(top: u32, right: u32, bottom: u32, left: u32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+    ///
+    /// The same space on all sides
+    ///
+    pub fn uniform(width: u32) -> Self {
+        Self::new(width, width, width, width)
+    }
+    ///
+    /// Remove the space from the given area
+    ///
+    pub fn shrink(&self, area: Rectangle) -> Rectangle {
+        let Rectangle {
+            top_left: Point { x, y },
+            size: Size { width, height },
+        } = area;
+        let total = self.total();
+        Rectangle {
+            top_left: Point {
+                x: x + self.left as i32,
+                y: y + self.top as i32,
+            },
+            size: Size {
+                width: width.saturating_sub(total.width),
+                height: height.saturating_sub(total.height),
+            },
+        }
+    }
+    ///
+    /// Sum of the horizontal and vertical space
+    ///
+    pub fn total(&self) -> Size {
+        Size {
+            width: self.left.saturating_add(self.right),
+            height: self.top.saturating_add(self.bottom),
+        }
+    }
+}
+
 struct Bordered<L: Layoutable<C>, C: PixelColor, D: Decorator<C>> {
     layoutable: L,
     decorator: D,
@@ -59,12 +159,9 @@ pub fn bordered<L: Layoutable<C>, C: PixelColor, D: Decorator<C>>(
 
 impl<L: Layoutable<C>, C: PixelColor, D: Decorator<C>> Layoutable<C> for Bordered<L, C, D> {
     fn size(&self) -> ComponentSize {
-        let ComponentSize { width, height } = self.layoutable.size();
-        let offset = Saturating(self.decorator.width() * 2);
-        ComponentSize {
-            width: width + offset,
-            height: height + offset,
-        }
+        let Size { width, height } = self.decorator.minimum_size();
+        (self.layoutable.size() + self.decorator.insets().total())
+            .union(ComponentSize::fixed_size(width, height))
     }
 
     fn draw_placed<DrawError>(
@@ -72,23 +169,32 @@ impl<L: Layoutable<C>, C: PixelColor, D: Decorator<C>> Layoutable<C> for Bordere
         target: &mut impl DrawTarget<Color = C, Error = DrawError>,
         position: Rectangle,
     ) -> Result<(), DrawError> {
-        let border = self.decorator.width();
-        let Rectangle {
-            top_left: Point { x, y },
-            size: Size { width, height },
-        } = position;
-        let inner_position = Rectangle {
-            top_left: Point {
-                x: x + border as i32,
-                y: y + border as i32,
-            },
-            size: Size {
-                width: width - 2 * border,
-                height: height - 2 * border,
-            },
-        };
         self.decorator.draw_placed(target, position)?;
-        self.layoutable.draw_placed(target, inner_position)
+        self.layoutable
+            .draw_placed(target, self.inner_position(position))
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(
+            LayoutNode::new(&self.layoutable, self.inner_position(position))
+                .with_insets(self.decorator.insets().total()),
+        );
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        self.layoutable
+            .collect_dirty(self.inner_position(position), dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.layoutable
+            .handle_event(self.inner_position(position), event)
+    }
+}
+
+impl<L: Layoutable<C>, C: PixelColor, D: Decorator<C>> Bordered<L, C, D> {
+    fn inner_position(&self, position: Rectangle) -> Rectangle {
+        self.decorator.insets().shrink(position)
     }
 }
 pub struct DashedLine<C: PixelColor> {
@@ -126,8 +232,12 @@ impl<C: PixelColor> DashedLine<C> {
 }
 
 impl<C: PixelColor> Decorator<C> for DashedLine<C> {
-    fn width(&self) -> u32 {
-        1
+    fn insets(&self) -> Insets {
+        Insets::uniform(1)
+    }
+
+    fn fill_area(&self, position: Rectangle) -> Rectangle {
+        position.offset(-1)
     }
 
     fn draw_placed<DrawError>(
@@ -136,6 +246,9 @@ impl<C: PixelColor> Decorator<C> for DashedLine<C> {
         position: Rectangle,
     ) -> Result<(), DrawError> {
         let sequence_length = self.dot_count + self.gap_count;
+        if sequence_length == 0 {
+            return Ok(());
+        }
         let Point { x: sx, y: sy } = position.top_left;
         let Size { width, height } = position.size;
         let ex = sx + width as i32 - 1;
@@ -196,8 +309,12 @@ impl<C: PixelColor> RoundedLine<C> {
 }
 
 impl<C: PixelColor> Decorator<C> for RoundedLine<C> {
-    fn width(&self) -> u32 {
-        2
+    fn insets(&self) -> Insets {
+        Insets::uniform(2)
+    }
+
+    fn fill_area(&self, position: Rectangle) -> Rectangle {
+        position.offset(-1)
     }
 
     fn draw_placed<DrawError>(
@@ -254,3 +371,664 @@ impl<C: PixelColor> Decorator<C> for RoundedLine<C> {
         )
     }
 }
+
+#[derive(Copy, Clone, Debug)]
+pub struct RoundedRectangleLine<C: PixelColor> {
+    color: C,
+    fill_color: Option<C>,
+    stroke_width: u32,
+    corners: CornerRadii,
+    stroke_alignment: StrokeAlignment,
+}
+
+impl<C: PixelColor> RoundedRectangleLine<C> {
+    ///
+    /// Create a solid line of a given thickness around a element
+    ///
+    /// The corners are square until a radius is set with `with_radius` or `with_corner_radii`. The line is drawn
+    /// inside of the placed area, so the content is moved inwards by the stroke width.
+    ///
+    /// # Arguments
+    ///
+    /// * `color`: Color of the line
+    /// * `stroke_width`: thickness of the line in pixels
+    ///
+    /// returns: RoundedRectangleLine<C>
+    ///
+    /// # Examples
+    ///
+    /// draw a 3 pixel thick frame with rounded corners of a radius of 5 pixels
+    /// ```
+    /// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+    /// use embedded_graphics::mono_font::MonoTextStyle;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use simple_layout::prelude::{bordered, center, owned_text, Layoutable, RoundedRectangleLine};
+    /// let frame = bordered(
+    ///     center(owned_text("Power", MonoTextStyle::new(&FONT_6X12, BinaryColor::On))),
+    ///     RoundedRectangleLine::new(BinaryColor::On, 3).with_radius(5),
+    /// );
+    /// // the text keeps a distance of 4 pixels on each side to stay inside the rounded corners
+    /// assert_eq!(frame.size().min_size().width, 29 + 2 * 4);
+    /// ```
+    pub fn new(color: C, stroke_width: u32) -> Self {
+        Self {
+            color,
+            fill_color: None,
+            stroke_width,
+            corners: CornerRadii::new(Size::zero()),
+            stroke_alignment: StrokeAlignment::Inside,
+        }
+    }
+    ///
+    /// Round all corners with the same radius
+    ///
+    pub fn with_radius(self, radius: u32) -> Self {
+        Self {
+            corners: CornerRadii::new(Size::new(radius, radius)),
+            ..self
+        }
+    }
+    ///
+    /// Define the radius of every corner independently
+    ///
+    pub fn with_corner_radii(self, corners: CornerRadii) -> Self {
+        Self { corners, ..self }
+    }
+    ///
+    /// Fill the area within the line including the rounded corners with a solid color
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+    /// use embedded_graphics::mono_font::MonoTextStyle;
+    /// use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+    /// use simple_layout::prelude::{bordered, owned_text, RoundedRectangleLine};
+    /// let highlighted = bordered(
+    ///     owned_text("Alarm", MonoTextStyle::new(&FONT_6X12, Rgb565::WHITE)),
+    ///     RoundedRectangleLine::new(Rgb565::WHITE, 1)
+    ///         .with_radius(4)
+    ///         .with_fill(Rgb565::RED),
+    /// );
+    /// ```
+    pub fn with_fill(self, fill_color: C) -> Self {
+        Self {
+            fill_color: Some(fill_color),
+            ..self
+        }
+    }
+    ///
+    /// Define if the line is drawn inside, centered on or outside the border of the placed area
+    ///
+    /// Only the part of the line inside of the placed area is reserved, a line outside overlaps the neighbours of
+    /// the element.
+    ///
+    pub fn with_stroke_alignment(self, stroke_alignment: StrokeAlignment) -> Self {
+        Self {
+            stroke_alignment,
+            ..self
+        }
+    }
+    fn inside_stroke_width(&self) -> u32 {
+        match self.stroke_alignment {
+            StrokeAlignment::Inside => self.stroke_width,
+            StrokeAlignment::Center => self.stroke_width.saturating_add(1) / 2,
+            StrokeAlignment::Outside => 0,
+        }
+    }
+    fn max_radius(&self) -> u32 {
+        let CornerRadii {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        } = self.corners;
+        [top_left, top_right, bottom_right, bottom_left]
+            .iter()
+            .map(|s| s.width.max(s.height))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl<C: PixelColor> Decorator<C> for RoundedRectangleLine<C> {
+    fn insets(&self) -> Insets {
+        // the corners of the content must stay within the inner curve of the line
+        let stroke = self.inside_stroke_width();
+        let inner_radius = self.max_radius().saturating_sub(stroke);
+        let center = stroke + inner_radius;
+        Insets::uniform(center - (inner_radius as f32 * FRAC_1_SQRT_2).floor() as u32)
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(self.color)
+            .stroke_width(self.stroke_width)
+            .stroke_alignment(self.stroke_alignment);
+        let style = if let Some(fill_color) = self.fill_color {
+            style.fill_color(fill_color)
+        } else {
+            style
+        };
+        RoundedRectangle::new(position, self.corners)
+            .into_styled(style.build())
+            .draw(target)
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+pub struct SideLine<C: PixelColor> {
+    color: C,
+    stroke_width: u32,
+    side: Side,
+}
+
+impl<C: PixelColor> SideLine<C> {
+    ///
+    /// Draw a line above the element
+    ///
+    /// # Arguments
+    ///
+    /// * `color`: Color of the line
+    /// * `stroke_width`: thickness of the line in pixels
+    ///
+    /// returns: SideLine<C>
+    ///
+    pub fn top(color: C, stroke_width: u32) -> Self {
+        Self {
+            color,
+            stroke_width,
+            side: Side::Top,
+        }
+    }
+    ///
+    /// Draw a line right of the element
+    ///
+    pub fn right(color: C, stroke_width: u32) -> Self {
+        Self {
+            color,
+            stroke_width,
+            side: Side::Right,
+        }
+    }
+    ///
+    /// Draw a line below the element (underline)
+    ///
+    /// # Examples
+    ///
+    /// underline a header
+    /// ```
+    /// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+    /// use embedded_graphics::mono_font::MonoTextStyle;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use simple_layout::prelude::{bordered, owned_text, Layoutable, SideLine};
+    /// let header = bordered(
+    ///     owned_text("Network", MonoTextStyle::new(&FONT_6X12, BinaryColor::On)),
+    ///     SideLine::bottom(BinaryColor::On, 2),
+    /// );
+    /// assert_eq!(header.size().min_size().height, 12 + 2);
+    /// assert_eq!(header.size().min_size().width, 41);
+    /// ```
+    pub fn bottom(color: C, stroke_width: u32) -> Self {
+        Self {
+            color,
+            stroke_width,
+            side: Side::Bottom,
+        }
+    }
+    ///
+    /// Draw a line left of the element (accent bar)
+    ///
+    pub fn left(color: C, stroke_width: u32) -> Self {
+        Self {
+            color,
+            stroke_width,
+            side: Side::Left,
+        }
+    }
+}
+
+impl<C: PixelColor> Decorator<C> for SideLine<C> {
+    fn insets(&self) -> Insets {
+        let w = self.stroke_width;
+        match self.side {
+            Side::Top => Insets::new(w, 0, 0, 0),
+            Side::Right => Insets::new(0, w, 0, 0),
+            Side::Bottom => Insets::new(0, 0, w, 0),
+            Side::Left => Insets::new(0, 0, 0, w),
+        }
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let Rectangle {
+            top_left: Point { x, y },
+            size: Size { width, height },
+        } = position;
+        let w = self.stroke_width;
+        let line = match self.side {
+            Side::Top => Rectangle::new(Point { x, y }, Size::new(width, w.min(height))),
+            Side::Right => Rectangle::new(
+                Point {
+                    x: x + width.saturating_sub(w) as i32,
+                    y,
+                },
+                Size::new(w.min(width), height),
+            ),
+            Side::Bottom => Rectangle::new(
+                Point {
+                    x,
+                    y: y + height.saturating_sub(w) as i32,
+                },
+                Size::new(width, w.min(height)),
+            ),
+            Side::Left => Rectangle::new(Point { x, y }, Size::new(w.min(width), height)),
+        };
+        target.fill_solid(&line, self.color)
+    }
+}
+
+pub struct DoubleLine<C: PixelColor> {
+    color: C,
+    gap: u32,
+}
+
+impl<C: PixelColor> DoubleLine<C> {
+    ///
+    /// Draw two parallel lines of 1 pixel around a element
+    ///
+    /// # Arguments
+    ///
+    /// * `color`: Color of the lines
+    /// * `gap`: Space between the lines in pixels
+    ///
+    /// returns: DoubleLine<C>
+    ///
+    pub fn new(color: C, gap: u32) -> Self {
+        Self { color, gap }
+    }
+}
+
+impl<C: PixelColor> Decorator<C> for DoubleLine<C> {
+    fn insets(&self) -> Insets {
+        Insets::uniform(self.gap.saturating_add(2))
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(self.color)
+            .stroke_width(1)
+            .stroke_alignment(StrokeAlignment::Inside)
+            .build();
+        position.into_styled(style).draw(target)?;
+        position
+            .offset(-(self.gap as i32 + 1))
+            .into_styled(style)
+            .draw(target)
+    }
+}
+
+pub struct BevelLine<C: PixelColor> {
+    light_color: C,
+    shadow_color: C,
+    stroke_width: u32,
+}
+
+impl<C: PixelColor> BevelLine<C> {
+    ///
+    /// Draw a 3D frame which looks raised out of the surface (light on top and left, shadow on bottom and right)
+    ///
+    /// # Arguments
+    ///
+    /// * `light_color`: Color of the lit edges
+    /// * `shadow_color`: Color of the edges in the shadow
+    /// * `stroke_width`: thickness of the frame in pixels
+    ///
+    /// returns: BevelLine<C>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+    /// use embedded_graphics::mono_font::MonoTextStyle;
+    /// use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+    /// use simple_layout::prelude::{bordered, owned_text, BevelLine};
+    /// let button = bordered(
+    ///     owned_text("OK", MonoTextStyle::new(&FONT_6X12, Rgb565::BLACK)),
+    ///     BevelLine::raised(Rgb565::WHITE, Rgb565::new(12, 24, 12), 2),
+    /// );
+    /// ```
+    pub fn raised(light_color: C, shadow_color: C, stroke_width: u32) -> Self {
+        Self {
+            light_color,
+            shadow_color,
+            stroke_width,
+        }
+    }
+    ///
+    /// Draw a 3D frame which looks pressed into the surface (shadow on top and left, light on bottom and right)
+    ///
+    pub fn sunken(light_color: C, shadow_color: C, stroke_width: u32) -> Self {
+        Self {
+            light_color: shadow_color,
+            shadow_color: light_color,
+            stroke_width,
+        }
+    }
+}
+
+impl<C: PixelColor> Decorator<C> for BevelLine<C> {
+    fn insets(&self) -> Insets {
+        Insets::uniform(self.stroke_width)
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let Point { x: sx, y: sy } = position.top_left;
+        let Size { width, height } = position.size;
+        let ex = sx + width as i32 - 1;
+        let ey = sy + height as i32 - 1;
+        let depth = (self.stroke_width as i32)
+            .min(width as i32 / 2)
+            .min(height as i32 / 2);
+        target.draw_iter((0..depth).flat_map(|i| {
+            (sx + i..ex - i)
+                .map(move |x| Pixel(Point { x, y: sy + i }, self.light_color))
+                .chain(
+                    (sy + i + 1..=ey - i)
+                        .map(move |y| Pixel(Point { x: sx + i, y }, self.light_color)),
+                )
+                .chain(
+                    (sx + i + 1..=ex - i)
+                        .map(move |x| Pixel(Point { x, y: ey - i }, self.shadow_color)),
+                )
+                .chain(
+                    (sy + i..ey - i).map(move |y| Pixel(Point { x: ex - i, y }, self.shadow_color)),
+                )
+        }))
+    }
+}
+
+///
+/// Fill the area enclosed by a decorator before the decorator and the element are drawn
+///
+/// The filled area is rectangular, use `RoundedRectangleLine::with_fill` for filled rounded corners.
+///
+/// # Arguments
+///
+/// * `decorator`: decorator drawn around the filled area
+/// * `paint`: color or pattern to fill the area
+///
+/// returns: impl Decorator<C>+Sized
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use simple_layout::prelude::{bordered, filled, owned_text, DitherPattern, RoundedLine};
+/// let panel = bordered(
+///     owned_text("Power", MonoTextStyle::new(&FONT_6X12, BinaryColor::On)),
+///     filled(RoundedLine::new(BinaryColor::On), DitherPattern::new(2)),
+/// );
+/// ```
+pub fn filled<C: PixelColor, D: Decorator<C>, P: Paint<C>>(
+    decorator: D,
+    paint: P,
+) -> impl Decorator<C> {
+    Filled {
+        decorator,
+        paint,
+        p: PhantomData,
+    }
+}
+
+struct Filled<C: PixelColor, D: Decorator<C>, P: Paint<C>> {
+    decorator: D,
+    paint: P,
+    p: PhantomData<C>,
+}
+
+impl<C: PixelColor, D: Decorator<C>, P: Paint<C>> Decorator<C> for Filled<C, D, P> {
+    fn insets(&self) -> Insets {
+        self.decorator.insets()
+    }
+
+    fn fill_area(&self, position: Rectangle) -> Rectangle {
+        self.decorator.fill_area(position)
+    }
+
+    fn minimum_size(&self) -> Size {
+        self.decorator.minimum_size()
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        self.paint
+            .fill(target, self.decorator.fill_area(position))?;
+        self.decorator.draw_placed(target, position)
+    }
+}
+
+const TITLE_INDENT: u32 = 3;
+const TITLE_GAP: u32 = 1;
+
+pub struct TitledLine<S: TextRenderer<Color = C> + Copy, C: PixelColor> {
+    title: Box<str>,
+    character_style: S,
+    color: C,
+}
+
+impl<S: TextRenderer<Color = C> + Copy, C: PixelColor> TitledLine<S, C> {
+    ///
+    /// Create a frame with a caption embedded into the top line (group box)
+    ///
+    /// # Arguments
+    ///
+    /// * `title`: caption of the frame
+    /// * `character_style`: Font and style of the caption
+    /// * `color`: Color of the line
+    ///
+    /// returns: TitledLine<S, C>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+    /// use embedded_graphics::mono_font::MonoTextStyle;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use simple_layout::prelude::{bordered, owned_text, Layoutable, TitledLine};
+    /// const TEXT_STYLE: MonoTextStyle<BinaryColor> = MonoTextStyle::new(&FONT_6X12, BinaryColor::On);
+    /// let group = bordered(
+    ///     owned_text("on", TEXT_STYLE),
+    ///     TitledLine::new("Network", TEXT_STYLE, BinaryColor::On),
+    /// );
+    /// let size = group.size();
+    /// // the frame is as wide as the title
+    /// assert_eq!(size.min_size().width, 42 + 2 * 4);
+    /// // the title is placed above the content
+    /// assert_eq!(size.min_size().height, 12 + 12 + 1);
+    /// ```
+    pub fn new(title: impl Into<Box<str>>, character_style: S, color: C) -> Self {
+        Self {
+            title: title.into(),
+            character_style,
+            color,
+        }
+    }
+    fn title_size(&self) -> Size {
+        self.character_style
+            .measure_string(&self.title, Point::zero(), Baseline::Top)
+            .bounding_box
+            .size
+    }
+}
+
+impl<S: TextRenderer<Color = C> + Copy, C: PixelColor> Decorator<C> for TitledLine<S, C> {
+    fn insets(&self) -> Insets {
+        Insets::new(self.title_size().height.max(1), 1, 1, 1)
+    }
+
+    fn minimum_size(&self) -> Size {
+        let Size { width, height } = self.title_size();
+        Size::new(width + 2 * (TITLE_INDENT + TITLE_GAP), height + 1)
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let Point { x: sx, y: sy } = position.top_left;
+        let Size { width, height } = position.size;
+        let title_size = self.title_size();
+        let line_offset = title_size.height / 2;
+        let ly = sy + line_offset as i32;
+        let ey = sy + height as i32 - 1;
+        let ex = sx + width as i32 - 1;
+        let title_start = TITLE_INDENT + TITLE_GAP;
+        let title_end = title_start + title_size.width + TITLE_GAP;
+        let frame_height = height.saturating_sub(line_offset + 2);
+        for line in [
+            Rectangle::new(Point::new(sx, ly), Size::new(TITLE_INDENT.min(width), 1)),
+            Rectangle::new(
+                Point::new(sx + title_end as i32, ly),
+                Size::new(width.saturating_sub(title_end), 1),
+            ),
+            Rectangle::new(Point::new(sx, ly + 1), Size::new(1, frame_height)),
+            Rectangle::new(Point::new(ex, ly + 1), Size::new(1, frame_height)),
+            Rectangle::new(Point::new(sx, ey), Size::new(width, 1)),
+        ] {
+            target.fill_solid(&line, self.color)?;
+        }
+        Text::with_text_style(
+            &self.title,
+            Point::new(sx + title_start as i32, sy),
+            self.character_style,
+            TextStyleBuilder::new().baseline(Baseline::Top).build(),
+        )
+        .draw(target)?;
+        Ok(())
+    }
+}
+
+///
+/// Draw a shadow offset to the bottom right of a decorated element, so it looks elevated above its surroundings
+///
+/// The shadow is drawn only where it is not covered by the element, so it can be combined with elements without
+/// a background.
+///
+/// # Arguments
+///
+/// * `decorator`: decorator drawn around the element
+/// * `paint`: color or pattern of the shadow
+/// * `offset`: distance of the shadow to the element in pixels (elevation)
+///
+/// returns: impl Decorator<C>+Sized
+///
+/// # Examples
+///
+/// a popup with a rounded border and a dithered shadow on a monochrome display
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use simple_layout::prelude::{bordered, owned_text, shadowed, DitherPattern, Layoutable, RoundedLine};
+/// let popup = bordered(
+///     owned_text("Saved", MonoTextStyle::new(&FONT_6X12, BinaryColor::On)),
+///     shadowed(RoundedLine::new(BinaryColor::On), DitherPattern::checkerboard(), 2),
+/// );
+/// assert_eq!(popup.size().min_size().width, 29 + 2 * 2 + 2);
+/// ```
+pub fn shadowed<C: PixelColor, D: Decorator<C>, P: Paint<C>>(
+    decorator: D,
+    paint: P,
+    offset: u32,
+) -> impl Decorator<C> {
+    Shadowed {
+        decorator,
+        paint,
+        offset,
+        p: PhantomData,
+    }
+}
+
+struct Shadowed<C: PixelColor, D: Decorator<C>, P: Paint<C>> {
+    decorator: D,
+    paint: P,
+    offset: u32,
+    p: PhantomData<C>,
+}
+
+impl<C: PixelColor, D: Decorator<C>, P: Paint<C>> Shadowed<C, D, P> {
+    fn element_area(&self, position: Rectangle) -> Rectangle {
+        Insets::new(0, self.offset, self.offset, 0).shrink(position)
+    }
+}
+
+impl<C: PixelColor, D: Decorator<C>, P: Paint<C>> Decorator<C> for Shadowed<C, D, P> {
+    fn insets(&self) -> Insets {
+        let Insets {
+            top,
+            right,
+            bottom,
+            left,
+        } = self.decorator.insets();
+        Insets::new(top, right + self.offset, bottom + self.offset, left)
+    }
+
+    fn fill_area(&self, position: Rectangle) -> Rectangle {
+        self.decorator.fill_area(self.element_area(position))
+    }
+
+    fn minimum_size(&self) -> Size {
+        self.decorator.minimum_size() + Size::new(self.offset, self.offset)
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let Point { x, y } = position.top_left;
+        let Size { width, height } = position.size;
+        let offset = self.offset.min(width).min(height);
+        let right_shadow = Rectangle::new(
+            Point::new(x + (width - offset) as i32, y + offset as i32),
+            Size::new(offset, height - offset),
+        );
+        let bottom_shadow = Rectangle::new(
+            Point::new(x + offset as i32, y + (height - offset) as i32),
+            Size::new(width.saturating_sub(2 * offset), offset),
+        );
+        self.paint.fill(target, right_shadow)?;
+        self.paint.fill(target, bottom_shadow)?;
+        self.decorator
+            .draw_placed(target, self.element_area(position))
+    }
+}
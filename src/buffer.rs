@@ -0,0 +1,290 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    primitives::Rectangle,
+    Pixel,
+};
+
+///
+/// Double buffered wrapper around a display which only sends changed pixels
+///
+/// Everything drawn onto this target is recorded into a frame buffer. `flush` compares the frame with the last
+/// flushed frame and sends every row span of changed pixels with a single `fill_contiguous` call to the display.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Dimensions, DrawTarget};
+/// use simple_layout::prelude::{scale, DoubleBuffer, Layoutable};
+/// let mut display = DoubleBuffer::new(MockDisplay::<BinaryColor>::new());
+/// let area = display.bounding_box();
+/// let mut sent = Vec::new();
+/// for value in [0.2, 0.4] {
+///     display.clear(BinaryColor::Off).unwrap();
+///     scale(value, BinaryColor::On).draw_placed(&mut display, area).unwrap();
+///     sent.push(display.flush().unwrap());
+///     display.inner_mut().set_allow_overdraw(true);
+/// }
+/// assert_eq!(sent[0], 64 * 64);
+/// // only the moved marker of the scale is sent again
+/// assert!(sent[1] > 0 && sent[1] < 64);
+/// ```
+pub struct DoubleBuffer<T: DrawTarget> {
+    target: T,
+    area: Rectangle,
+    frame: Box<[Option<T::Color>]>,
+    flushed: Box<[Option<T::Color>]>,
+}
+
+impl<T: DrawTarget> DoubleBuffer<T> {
+    ///
+    /// Wrap a display, the buffer covers the bounding box of the display
+    ///
+    /// # Arguments
+    ///
+    /// * `target`: display to send the changed pixels to
+    ///
+    /// returns: DoubleBuffer<T>
+    ///
+    pub fn new(target: T) -> Self {
+        let area = target.bounding_box();
+        let pixel_count = (area.size.width as usize)
+            .checked_mul(area.size.height as usize)
+            .expect("display too large for a frame buffer");
+        Self {
+            target,
+            area,
+            frame: vec![None; pixel_count].into_boxed_slice(),
+            flushed: vec![None; pixel_count].into_boxed_slice(),
+        }
+    }
+    ///
+    /// Send all pixels which changed since the last flush to the display
+    ///
+    /// Pixels never drawn are left untouched on the display.
+    ///
+    /// returns: Result<usize, T::Error> count of sent pixels
+    ///
+    pub fn flush(&mut self) -> Result<usize, T::Error> {
+        let Size { width, height } = self.area.size;
+        let mut sent = 0;
+        for y in 0..height as usize {
+            let row = y * width as usize..(y + 1) * width as usize;
+            let frame = &self.frame[row.clone()];
+            let flushed = &self.flushed[row.clone()];
+            let mut x = 0;
+            while x < frame.len() {
+                if frame[x].is_none() || frame[x] == flushed[x] {
+                    x += 1;
+                    continue;
+                }
+                let start = x;
+                while x < frame.len() && frame[x].is_some() && frame[x] != flushed[x] {
+                    x += 1;
+                }
+                self.target.fill_contiguous(
+                    &Rectangle::new(
+                        self.area.top_left + Point::new(start as i32, y as i32),
+                        Size::new((x - start) as u32, 1),
+                    ),
+                    frame[start..x].iter().flatten().copied(),
+                )?;
+                sent += x - start;
+            }
+            for (flushed, frame) in self.flushed[row.clone()]
+                .iter_mut()
+                .zip(self.frame[row].iter())
+            {
+                if frame.is_some() {
+                    *flushed = *frame;
+                }
+            }
+        }
+        Ok(sent)
+    }
+    ///
+    /// Forget the content of the display, the next flush sends every drawn pixel
+    ///
+    pub fn invalidate(&mut self) {
+        self.flushed.fill(None);
+    }
+    /// Access the wrapped display
+    pub fn inner(&self) -> &T {
+        &self.target
+    }
+    /// Access the wrapped display, e.g. to change settings
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.target
+    }
+    /// Release the wrapped display
+    pub fn into_inner(self) -> T {
+        self.target
+    }
+    fn index_of(&self, point: Point) -> Option<usize> {
+        if self.area.contains(point) {
+            let Point { x, y } = point - self.area.top_left;
+            Some(y as usize * self.area.size.width as usize + x as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: DrawTarget> Dimensions for DoubleBuffer<T> {
+    fn bounding_box(&self) -> Rectangle {
+        self.area
+    }
+}
+
+impl<T: DrawTarget> DrawTarget for DoubleBuffer<T> {
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let Some(idx) = self.index_of(point) {
+                self.frame[idx] = Some(color);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use embedded_graphics::{
+        draw_target::DrawTarget,
+        geometry::{Dimensions, Point, Size},
+        pixelcolor::BinaryColor,
+        primitives::Rectangle,
+        Pixel,
+    };
+
+    use super::DoubleBuffer;
+
+    /// display recording every span sent to it
+    struct Recorder {
+        area: Rectangle,
+        spans: Vec<(Rectangle, Vec<BinaryColor>)>,
+    }
+
+    impl Recorder {
+        fn new(top_left: Point) -> Self {
+            Self {
+                area: Rectangle::new(top_left, Size::new(8, 3)),
+                spans: Vec::new(),
+            }
+        }
+    }
+
+    impl Dimensions for Recorder {
+        fn bounding_box(&self) -> Rectangle {
+            self.area
+        }
+    }
+
+    impl DrawTarget for Recorder {
+        type Color = BinaryColor;
+        type Error = Infallible;
+
+        fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            unreachable!("the buffer sends spans only")
+        }
+
+        fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Self::Color>,
+        {
+            self.spans.push((*area, colors.into_iter().collect()));
+            Ok(())
+        }
+    }
+
+    fn span(x: i32, y: i32, colors: &[BinaryColor]) -> (Rectangle, Vec<BinaryColor>) {
+        (
+            Rectangle::new(Point::new(x, y), Size::new(colors.len() as u32, 1)),
+            colors.to_vec(),
+        )
+    }
+
+    fn draw(buffer: &mut DoubleBuffer<Recorder>, pixels: &[(i32, i32, BinaryColor)]) {
+        buffer
+            .draw_iter(
+                pixels
+                    .iter()
+                    .map(|(x, y, color)| Pixel(Point::new(*x, *y), *color)),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn sends_drawn_spans_per_row() {
+        use BinaryColor::{Off, On};
+        let mut buffer = DoubleBuffer::new(Recorder::new(Point::zero()));
+        draw(
+            &mut buffer,
+            &[(1, 0, On), (2, 0, Off), (5, 0, On), (7, 2, On), (0, 1, Off)],
+        );
+        assert_eq!(buffer.flush().unwrap(), 5);
+        assert_eq!(
+            buffer.inner().spans,
+            vec![
+                span(1, 0, &[On, Off]),
+                span(5, 0, &[On]),
+                span(0, 1, &[Off]),
+                span(7, 2, &[On])
+            ]
+        );
+    }
+
+    #[test]
+    fn sends_only_changed_pixels() {
+        use BinaryColor::{Off, On};
+        let mut buffer = DoubleBuffer::new(Recorder::new(Point::zero()));
+        draw(
+            &mut buffer,
+            &[(0, 0, On), (1, 0, On), (2, 0, On), (3, 0, On)],
+        );
+        buffer.flush().unwrap();
+        buffer.inner_mut().spans.clear();
+
+        assert_eq!(buffer.flush().unwrap(), 0);
+        draw(
+            &mut buffer,
+            &[(0, 0, On), (1, 0, Off), (2, 0, Off), (3, 0, On)],
+        );
+        assert_eq!(buffer.flush().unwrap(), 2);
+        assert_eq!(buffer.inner().spans, vec![span(1, 0, &[Off, Off])]);
+    }
+
+    #[test]
+    fn invalidate_sends_everything_again() {
+        use BinaryColor::On;
+        let mut buffer = DoubleBuffer::new(Recorder::new(Point::zero()));
+        draw(&mut buffer, &[(2, 1, On), (3, 1, On)]);
+        buffer.flush().unwrap();
+        buffer.invalidate();
+        assert_eq!(buffer.flush().unwrap(), 2);
+        assert_eq!(buffer.inner().spans[1], span(2, 1, &[On, On]));
+    }
+
+    #[test]
+    fn keeps_offset_of_display() {
+        use BinaryColor::On;
+        let mut buffer = DoubleBuffer::new(Recorder::new(Point::new(10, 20)));
+        // outside of the display
+        draw(&mut buffer, &[(0, 0, On), (12, 21, On)]);
+        assert_eq!(buffer.flush().unwrap(), 1);
+        assert_eq!(buffer.inner().spans, vec![span(12, 21, &[On])]);
+    }
+}
@@ -0,0 +1,451 @@
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::ops::DerefMut;
+use std::sync::Mutex;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::Size,
+    pixelcolor::PixelColor,
+    prelude::Primitive,
+    primitives::{CornerRadii, PrimitiveStyleBuilder, Rectangle, RoundedRectangle},
+    text::renderer::TextRenderer,
+    Drawable,
+};
+#[cfg(feature = "log")]
+use log::warn;
+
+use crate::align::center;
+use crate::border::Insets;
+use crate::color::map_color;
+use crate::dirty::DirtyRegion;
+use crate::event::{InputEvent, Key};
+use crate::focus::FocusManager;
+use crate::inspect::LayoutNode;
+use crate::layoutable::{owned_text, Layoutable};
+use crate::ComponentSize;
+
+/// space between the frame and the content of a button
+const BUTTON_PADDING: u32 = 1;
+
+///
+/// Appearance of a button in one of its states
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ButtonLook<C: PixelColor> {
+    /// color of the label or icon
+    pub content: C,
+    /// color of the area of the button, None keeps the background
+    pub fill: Option<C>,
+    /// color of the frame, None draws no frame
+    pub border: Option<C>,
+    /// thickness of the frame
+    pub border_width: u32,
+}
+
+///
+/// Appearance of a button in all its states
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ButtonStyle<C: PixelColor> {
+    /// idle button
+    pub normal: ButtonLook<C>,
+    /// button held down
+    pub pressed: ButtonLook<C>,
+    /// button selected by the focus
+    pub focused: ButtonLook<C>,
+    /// button which cannot be activated
+    pub disabled: ButtonLook<C>,
+    /// radius of the corners of the frame
+    pub corner_radius: u32,
+    /// minimum size of the button, so it can be hit with a finger
+    pub min_size: Size,
+}
+
+impl<C: PixelColor> ButtonStyle<C> {
+    ///
+    /// Framed button which is drawn inverted while pressed and with a thicker frame while focused
+    ///
+    /// Disabled buttons are drawn without frame. The minimum size is 24x24 pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `foreground`: Color of label and frame
+    /// * `background`: Color of the display background
+    ///
+    /// returns: ButtonStyle<C>
+    ///
+    pub fn new(foreground: C, background: C) -> Self {
+        let normal = ButtonLook {
+            content: foreground,
+            fill: None,
+            border: Some(foreground),
+            border_width: 1,
+        };
+        Self {
+            normal,
+            pressed: ButtonLook {
+                content: background,
+                fill: Some(foreground),
+                ..normal
+            },
+            focused: ButtonLook {
+                border_width: 2,
+                ..normal
+            },
+            disabled: ButtonLook {
+                border: None,
+                ..normal
+            },
+            corner_radius: 3,
+            min_size: Size::new(24, 24),
+        }
+    }
+    ///
+    /// Change the minimum size of the button
+    ///
+    pub fn with_min_size(self, min_size: Size) -> Self {
+        Self { min_size, ..self }
+    }
+    ///
+    /// Change the radius of the corners
+    ///
+    pub fn with_corner_radius(self, corner_radius: u32) -> Self {
+        Self {
+            corner_radius,
+            ..self
+        }
+    }
+    fn insets(&self) -> Insets {
+        let border_width = [self.normal, self.pressed, self.focused, self.disabled]
+            .iter()
+            .map(|look| look.border_width)
+            .max()
+            .unwrap_or(0);
+        Insets::uniform(border_width + BUTTON_PADDING)
+    }
+    /// color of a pixel of the content in the given state
+    fn content_color(&self, kind: LookKind, color: C) -> C {
+        match kind {
+            // the whole content is dimmed, also multicolored icons
+            LookKind::Disabled => self.disabled.content,
+            _ if color == self.normal.content => self.look(kind).content,
+            _ => color,
+        }
+    }
+    fn look(&self, kind: LookKind) -> ButtonLook<C> {
+        match kind {
+            LookKind::Normal => self.normal,
+            LookKind::Pressed => self.pressed,
+            LookKind::Focused => self.focused,
+            LookKind::Disabled => self.disabled,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum LookKind {
+    Normal,
+    Pressed,
+    Focused,
+    Disabled,
+}
+
+///
+/// State of a button kept between frames
+///
+#[derive(Debug, Default)]
+pub struct ButtonState {
+    pressed: Cell<bool>,
+    drawn: Cell<Option<(LookKind, Rectangle)>>,
+}
+
+impl ButtonState {
+    /// true while the button is held down
+    pub fn is_pressed(&self) -> bool {
+        self.pressed.get()
+    }
+}
+
+///
+/// Button showing any element as its content, e.g. a icon
+///
+/// Pixels of the content drawn in the content color of the normal look are replaced by the content color of the current
+/// state, all other colors (e.g. of a multicolored icon) are kept. While disabled, the whole content is drawn in the
+/// content color of the disabled look.
+/// The button is activated by releasing the pointer on it or by `Key::Enter` while it is focused. To learn where the
+/// button was placed, wrap it into `callback_placement` or `optional_placement`.
+///
+/// # Arguments
+///
+/// * `state`: state of the button kept between frames
+/// * `style`: appearance of the button
+/// * `on_activate`: callback called when the button is activated
+/// * `content`: element shown on the button
+///
+/// returns: Button<L, C, F, ()>
+///
+/// # Examples
+///
+/// disabled icon button, its place is reported by `optional_placement`
+/// ```
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::pixelcolor::{Rgb565, RgbColor, WebColors};
+/// use embedded_graphics::prelude::{Point, Primitive, Size};
+/// use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+/// use simple_layout::prelude::{button, optional_placement, ButtonState, ButtonStyle, Layoutable};
+/// let state = ButtonState::default();
+/// let mut style = ButtonStyle::new(Rgb565::WHITE, Rgb565::BLACK);
+/// style.disabled.content = Rgb565::CSS_GRAY;
+/// let icon = Rectangle::new(Point::zero(), Size::new(4, 4)).into_styled(PrimitiveStyle::with_fill(Rgb565::RED));
+/// let area = Rectangle::new(Point::zero(), Size::new(24, 24));
+/// let mut place = None;
+/// let mut display = MockDisplay::new();
+/// optional_placement(&mut place, button(&state, style, || {}, icon).disabled(true))
+///     .draw_placed(&mut display, area)
+///     .unwrap();
+/// // the red icon is dimmed while the button is disabled
+/// assert_eq!(display.get_pixel(Point::new(12, 12)), Some(Rgb565::CSS_GRAY));
+/// assert_eq!(place, Some(area));
+/// ```
+pub fn button<'a, L: Layoutable<C>, C: PixelColor, F: FnMut()>(
+    state: &'a ButtonState,
+    style: ButtonStyle<C>,
+    on_activate: F,
+    content: L,
+) -> Button<'a, impl Layoutable<C>, C, F, ()> {
+    Button {
+        state,
+        style,
+        on_activate: Mutex::new(on_activate),
+        content: center(content),
+        disabled: false,
+        focus: None,
+        p: PhantomData,
+    }
+}
+
+///
+/// Button with a text label
+///
+/// # Arguments
+///
+/// * `state`: state of the button kept between frames
+/// * `style`: appearance of the button
+/// * `on_activate`: callback called when the button is activated
+/// * `label`: text of the button
+/// * `character_style`: font of the label, text in the content color of the normal look follows the button state
+///
+/// returns: Button<impl Layoutable<C>, C, F, ()>
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{dispatch, text_button, ButtonState, ButtonStyle, InputEvent, Layoutable};
+/// let count = Cell::new(0);
+/// let state = ButtonState::default();
+/// let plus = text_button(
+///     &state,
+///     ButtonStyle::new(BinaryColor::On, BinaryColor::Off),
+///     || count.set(count.get() + 1),
+///     "+",
+///     MonoTextStyle::new(&FONT_6X12, BinaryColor::On),
+/// );
+/// // the label is small, but the button is large enough to be touched
+/// assert_eq!(plus.size().min_size(), Size::new(24, 24));
+/// let area = Rectangle::new(Point::zero(), Size::new(24, 24));
+/// dispatch(&plus, area, InputEvent::Press(Point::new(3, 3)));
+/// assert!(state.is_pressed());
+/// dispatch(&plus, area, InputEvent::Release(Point::new(4, 4)));
+/// assert_eq!(count.get(), 1);
+/// ```
+pub fn text_button<
+    'a,
+    S: TextRenderer<Color = C> + Copy,
+    C: PixelColor,
+    F: FnMut(),
+    StrValue: Into<Box<str>>,
+>(
+    state: &'a ButtonState,
+    style: ButtonStyle<C>,
+    on_activate: F,
+    label: StrValue,
+    character_style: S,
+) -> Button<'a, impl Layoutable<C>, C, F, ()> {
+    button(
+        state,
+        style,
+        on_activate,
+        owned_text(label, character_style),
+    )
+}
+
+///
+/// Clickable element with a frame and a activation callback, see `button` and `text_button`
+///
+pub struct Button<'a, L: Layoutable<C>, C: PixelColor, F: FnMut(), K: Copy + Eq> {
+    state: &'a ButtonState,
+    style: ButtonStyle<C>,
+    on_activate: Mutex<F>,
+    content: L,
+    disabled: bool,
+    focus: Option<(&'a FocusManager<K>, K)>,
+    p: PhantomData<C>,
+}
+
+impl<'a, L: Layoutable<C>, C: PixelColor, F: FnMut(), K: Copy + Eq> Button<'a, L, C, F, K> {
+    ///
+    /// Disable the button, it ignores all input and shows the disabled look
+    ///
+    pub fn disabled(self, disabled: bool) -> Self {
+        Self { disabled, ..self }
+    }
+    ///
+    /// Make the button reachable by navigation, it shows the focused look while focused
+    ///
+    /// # Arguments
+    ///
+    /// * `manager`: focus manager of the screen
+    /// * `key`: unique key of this button
+    ///
+    /// returns: Button<L, C, F, K>
+    ///
+    pub fn with_focus<K2: Copy + Eq>(
+        self,
+        manager: &'a FocusManager<K2>,
+        key: K2,
+    ) -> Button<'a, L, C, F, K2> {
+        Button {
+            state: self.state,
+            style: self.style,
+            on_activate: self.on_activate,
+            content: self.content,
+            disabled: self.disabled,
+            focus: Some((manager, key)),
+            p: PhantomData,
+        }
+    }
+    fn is_focused(&self) -> bool {
+        self.focus
+            .map(|(manager, key)| manager.is_focused(key))
+            .unwrap_or(false)
+    }
+    fn look_kind(&self) -> LookKind {
+        if self.disabled {
+            LookKind::Disabled
+        } else if self.state.is_pressed() {
+            LookKind::Pressed
+        } else if self.is_focused() {
+            LookKind::Focused
+        } else {
+            LookKind::Normal
+        }
+    }
+    fn inner_position(&self, position: Rectangle) -> Rectangle {
+        self.style.insets().shrink(position)
+    }
+    fn activate(&self) {
+        if let Ok(mut mutex) = self.on_activate.try_lock() {
+            (mutex.deref_mut())();
+        } else {
+            #[cfg(feature = "log")]
+            warn!("Cannot lock activation callback");
+        }
+    }
+}
+
+impl<'a, L: Layoutable<C>, C: PixelColor, F: FnMut(), K: Copy + Eq> Layoutable<C>
+    for Button<'a, L, C, F, K>
+{
+    fn size(&self) -> ComponentSize {
+        let Size { width, height } = self.style.min_size;
+        (self.content.size() + self.style.insets().total())
+            .union(ComponentSize::fixed_size(width, height))
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let kind = self.look_kind();
+        self.state.drawn.set(Some((kind, position)));
+        if let Some((manager, key)) = self.focus {
+            manager.register(key, position, kind == LookKind::Focused);
+        }
+        let look = self.style.look(kind);
+        let mut frame_style = PrimitiveStyleBuilder::new();
+        if let Some(fill) = look.fill {
+            frame_style = frame_style.fill_color(fill);
+        }
+        if let Some(border) = look.border {
+            frame_style = frame_style
+                .stroke_color(border)
+                .stroke_width(look.border_width);
+        }
+        let radius = self.style.corner_radius;
+        RoundedRectangle::new(position, CornerRadii::new(Size::new(radius, radius)))
+            .into_styled(frame_style.build())
+            .draw(target)?;
+        map_color(
+            move |color| self.style.content_color(kind, color),
+            &self.content,
+        )
+        .draw_placed(target, self.inner_position(position))
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(
+            LayoutNode::new(&self.content, self.inner_position(position))
+                .with_insets(self.style.insets().total()),
+        );
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        if self.state.drawn.get() == Some((self.look_kind(), position)) {
+            self.content
+                .collect_dirty(self.inner_position(position), dirty)
+        } else {
+            dirty.add(position)
+        }
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        if self.disabled {
+            self.state.pressed.set(false);
+            return false;
+        }
+        match event {
+            InputEvent::Press(point) if position.contains(point) => {
+                self.state.pressed.set(true);
+                if let Some((manager, key)) = self.focus {
+                    manager.focus(Some(key));
+                }
+                true
+            }
+            InputEvent::Release(point) => {
+                let was_pressed = self.state.pressed.replace(false);
+                if was_pressed && position.contains(point) {
+                    self.activate();
+                }
+                was_pressed
+            }
+            InputEvent::Move(point) if !position.contains(point) => {
+                // leaving the button while pressed cancels the activation
+                self.state.pressed.set(false);
+                false
+            }
+            InputEvent::Key(Key::Enter) if self.is_focused() => {
+                self.activate();
+                true
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+use embedded_graphics::{draw_target::DrawTarget, pixelcolor::PixelColor, primitives::Rectangle};
+
+use crate::dirty::DirtyRegion;
+use crate::event::InputEvent;
+use crate::inspect::LayoutNode;
+use crate::layoutable::Layoutable;
+use crate::ComponentSize;
+
+///
+/// Remember the size of a element after it was calculated the first time
+///
+/// Nested layouts query the sizes of their subtrees on every level and on every draw again. Wrapping an expensive
+/// subtree lets all following queries on the same element reuse the first result; trees created again for every frame
+/// start without a remembered size.
+///
+/// **Only for content which does not change its size.** The size is never calculated again by itself, so wrapping
+/// text, optional elements or anything else whose size depends on changing values freezes the first size. Call
+/// `Cached::invalidate` after such a change.
+///
+/// # Arguments
+///
+/// * `layoutable`: element whose size is remembered
+///
+/// returns: Cached<L, C>
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use simple_layout::prelude::{cached, horizontal_layout, owned_text, vertical_layout, Layoutable};
+/// let style = MonoTextStyle::new(&FONT_6X12, BinaryColor::On);
+/// let table = vertical_layout(
+///     cached(horizontal_layout(owned_text("Temperature", style), 1).append(owned_text("21.3°C", style), 0)),
+///     0,
+/// )
+/// .append(
+///     cached(horizontal_layout(owned_text("Humidity", style), 1).append(owned_text("45%", style), 0)),
+///     0,
+/// );
+/// assert_eq!(table.size().preferred_size().height, 24);
+/// ```
+pub fn cached<L: Layoutable<C>, C: PixelColor>(layoutable: L) -> Cached<L, C> {
+    Cached {
+        layoutable,
+        size: Cell::new(None),
+        p: PhantomData,
+    }
+}
+
+///
+/// Element remembering its size, see `cached`
+///
+pub struct Cached<L: Layoutable<C>, C: PixelColor> {
+    layoutable: L,
+    size: Cell<Option<ComponentSize>>,
+    p: PhantomData<C>,
+}
+
+impl<L: Layoutable<C>, C: PixelColor> Cached<L, C> {
+    ///
+    /// Forget the remembered size, the next query calculates it again
+    ///
+    pub fn invalidate(&self) {
+        self.size.set(None);
+    }
+}
+
+impl<L: Layoutable<C>, C: PixelColor> Layoutable<C> for Cached<L, C> {
+    fn size(&self) -> ComponentSize {
+        if let Some(size) = self.size.get() {
+            size
+        } else {
+            let size = self.layoutable.size();
+            self.size.set(Some(size));
+            size
+        }
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        self.layoutable.draw_placed(target, position)
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(LayoutNode::new(&self.layoutable, position));
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        self.layoutable.collect_dirty(position, dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.layoutable.handle_event(position, event)
+    }
+}
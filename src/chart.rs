@@ -0,0 +1,450 @@
+use std::borrow::Borrow;
+use std::cell::OnceCell;
+
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{Point, Size},
+    mono_font::MonoTextStyle,
+    pixelcolor::PixelColor,
+    primitives::{Line, PointsIter, Rectangle},
+    text::{renderer::TextRenderer, Baseline, Text, TextStyleBuilder},
+    Drawable, Pixel,
+};
+
+use crate::layoutable::Layoutable;
+use crate::{ComponentSize, ValueRange};
+
+/// smallest area the values are plotted into
+const MIN_PLOT_SIZE: Size = Size::new(11, 4);
+/// space between the labels and the plot
+const LABEL_GAP: u32 = 1;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum ChartKind {
+    Line,
+    Bar,
+}
+
+///
+/// Small line chart without axes to show a trend within text or tables
+///
+/// The chart takes all the space it gets, so it should be placed with a weight or into `expand`.
+///
+/// # Arguments
+///
+/// * `values`: values from the oldest to the newest, e.g. a slice or a `VecDeque` used as ring buffer
+/// * `color`: color of the line
+///
+/// returns: Chart<I, C, MonoTextStyle<C>>
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{sparkline, Layoutable};
+/// let mut history = VecDeque::from([1.0, 3.0, 2.0]);
+/// history.push_back(5.0);
+/// history.pop_front();
+/// let trend = sparkline(&history, BinaryColor::On);
+/// let mut display = MockDisplay::<BinaryColor>::new();
+/// trend
+///     .draw_placed(&mut display, Rectangle::new(Point::zero(), Size::new(5, 4)))
+///     .unwrap();
+/// display.assert_pattern(&[
+///     "    #",
+///     "   # ",
+///     "## # ",
+///     "  #  ",
+/// ]);
+/// ```
+pub fn sparkline<I: IntoIterator + Clone, C: PixelColor>(
+    values: I,
+    color: C,
+) -> Chart<I, C, MonoTextStyle<'static, C>>
+where
+    I::Item: Borrow<f32>,
+{
+    Chart {
+        values,
+        color,
+        kind: ChartKind::Line,
+        range: None,
+        axes: None,
+        labels: None,
+        precision: None,
+        label_texts: OnceCell::new(),
+    }
+}
+
+///
+/// Line chart with axes
+///
+/// # Arguments
+///
+/// * `values`: values from the oldest to the newest, e.g. a slice or a `VecDeque` used as ring buffer
+/// * `color`: color of the line and the axes
+///
+/// returns: Chart<I, C, MonoTextStyle<C>>
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_4X6;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::Size;
+/// use simple_layout::prelude::{line_chart, Layoutable};
+/// let temperatures = [18.5, 19.0, 21.0, 20.5];
+/// let chart = line_chart(&temperatures, BinaryColor::On)
+///     .with_range(15.0, 25.0)
+///     .with_labels(MonoTextStyle::new(&FONT_4X6, BinaryColor::On));
+/// // labels "25" and "15" and the y axis on the left of the plot
+/// assert_eq!(chart.size().min_size(), Size::new(8 + 1 + 1 + 11, 12 + 1));
+/// ```
+pub fn line_chart<I: IntoIterator + Clone, C: PixelColor>(
+    values: I,
+    color: C,
+) -> Chart<I, C, MonoTextStyle<'static, C>>
+where
+    I::Item: Borrow<f32>,
+{
+    sparkline(values, color).with_axes(color)
+}
+
+///
+/// Bar chart with axes, one bar per value
+///
+/// # Arguments
+///
+/// * `values`: values from the oldest to the newest, e.g. a slice or a `VecDeque` used as ring buffer
+/// * `color`: color of the bars and the axes
+///
+/// returns: Chart<I, C, MonoTextStyle<C>>
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{bar_chart, Layoutable};
+/// let consumption = [2.0, 4.0, 1.0];
+/// let chart = bar_chart(&consumption, BinaryColor::On).with_range(0.0, 4.0);
+/// let mut display = MockDisplay::<BinaryColor>::new();
+/// chart
+///     .draw_placed(&mut display, Rectangle::new(Point::zero(), Size::new(10, 5)))
+///     .unwrap();
+/// display.assert_pattern(&[
+///     "#   ##    ",
+///     "### ##    ",
+///     "### ## ## ",
+///     "### ## ## ",
+///     "##########",
+/// ]);
+/// ```
+pub fn bar_chart<I: IntoIterator + Clone, C: PixelColor>(
+    values: I,
+    color: C,
+) -> Chart<I, C, MonoTextStyle<'static, C>>
+where
+    I::Item: Borrow<f32>,
+{
+    Chart {
+        kind: ChartKind::Bar,
+        ..line_chart(values, color)
+    }
+}
+
+///
+/// Chart of a series of values, see `sparkline`, `line_chart` and `bar_chart`
+///
+/// The labels are formatted once when the chart is measured or drawn the first time.
+///
+pub struct Chart<I: IntoIterator + Clone, C: PixelColor, S: TextRenderer<Color = C> + Copy>
+where
+    I::Item: Borrow<f32>,
+{
+    values: I,
+    color: C,
+    kind: ChartKind,
+    range: Option<(f32, f32)>,
+    axes: Option<C>,
+    labels: Option<S>,
+    precision: Option<usize>,
+    label_texts: OnceCell<Option<(String, String)>>,
+}
+
+impl<I: IntoIterator + Clone, C: PixelColor, S: TextRenderer<Color = C> + Copy> Chart<I, C, S>
+where
+    I::Item: Borrow<f32>,
+{
+    ///
+    /// Use a fixed range for the y axis instead of the range of the values, values outside are clamped
+    ///
+    pub fn with_range(self, min: f32, max: f32) -> Self {
+        Self {
+            range: Some((min, max)),
+            label_texts: OnceCell::new(),
+            ..self
+        }
+    }
+    ///
+    /// Draw a y axis on the left and a x axis on the bottom of the plot
+    ///
+    pub fn with_axes(self, axes_color: C) -> Self {
+        Self {
+            axes: Some(axes_color),
+            ..self
+        }
+    }
+    ///
+    /// Show the maximum and the minimum of the y range left of the plot
+    ///
+    /// # Arguments
+    ///
+    /// * `character_style`: font and color of the labels
+    ///
+    /// returns: Chart<I, C, S2>
+    ///
+    pub fn with_labels<S2: TextRenderer<Color = C> + Copy>(
+        self,
+        character_style: S2,
+    ) -> Chart<I, C, S2> {
+        Chart {
+            values: self.values,
+            color: self.color,
+            kind: self.kind,
+            range: self.range,
+            axes: self.axes,
+            labels: Some(character_style),
+            precision: self.precision,
+            label_texts: OnceCell::new(),
+        }
+    }
+    ///
+    /// Show the labels with a fixed count of decimal places instead of the shortest exact representation
+    ///
+    /// A fixed precision keeps the width of the labels stable while the values change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::mono_font::iso_8859_1::FONT_4X6;
+    /// use embedded_graphics::mono_font::MonoTextStyle;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use simple_layout::prelude::{line_chart, Layoutable};
+    /// let style = MonoTextStyle::new(&FONT_4X6, BinaryColor::On);
+    /// let width = |values: &[f32]| {
+    ///     line_chart(values, BinaryColor::On).with_labels(style).with_label_precision(1).size().min_size().width
+    /// };
+    /// // "21.0" and "21.5" are as wide as "20.3" and "21.7"
+    /// assert_eq!(width(&[21.0, 21.5]), width(&[20.25, 21.7]));
+    /// ```
+    pub fn with_label_precision(self, decimals: usize) -> Self {
+        Self {
+            precision: Some(decimals),
+            label_texts: OnceCell::new(),
+            ..self
+        }
+    }
+    fn values(&self) -> impl Iterator<Item = f32> {
+        self.values.clone().into_iter().map(|v| *v.borrow())
+    }
+    /// range of the y axis, None if there is nothing to draw
+    fn y_range(&self) -> Option<(f32, f32)> {
+        self.range.or_else(|| {
+            self.values().fold(None, |range, v| match range {
+                _ if !v.is_finite() => range,
+                None => Some((v, v)),
+                Some((min, max)) => Some((v.min(min), v.max(max))),
+            })
+        })
+    }
+    /// texts of the maximum and the minimum label
+    fn label_texts(&self) -> Option<&(String, String)> {
+        self.label_texts
+            .get_or_init(|| {
+                self.labels?;
+                let (min, max) = self.y_range().unwrap_or((0.0, 0.0));
+                let format = |v: f32| match self.precision {
+                    Some(decimals) => format!("{v:.decimals$}"),
+                    None => format!("{v}"),
+                };
+                Some((format(max), format(min)))
+            })
+            .as_ref()
+    }
+    fn label_size(&self, text: &str) -> Size {
+        self.labels
+            .map(|style| {
+                style
+                    .measure_string(text, Point::zero(), Baseline::Top)
+                    .bounding_box
+                    .size
+            })
+            .unwrap_or_default()
+    }
+    /// width of the labels and the height of a single label
+    fn labels_size(&self) -> Size {
+        self.label_texts()
+            .map(|(max, min)| {
+                let max = self.label_size(max);
+                let min = self.label_size(min);
+                Size::new(
+                    max.width.max(min.width) + LABEL_GAP,
+                    max.height.max(min.height),
+                )
+            })
+            .unwrap_or_default()
+    }
+    fn axes_size(&self) -> u32 {
+        if self.axes.is_some() {
+            1
+        } else {
+            0
+        }
+    }
+    fn draw_values<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        plot: Rectangle,
+    ) -> Result<(), DrawError> {
+        let Some((min, max)) = self.y_range() else {
+            return Ok(());
+        };
+        let Rectangle {
+            top_left: Point { x: left, y: top },
+            size: Size { width, height },
+        } = plot;
+        let count = self.values().count() as u32;
+        if count == 0 || width == 0 || height == 0 {
+            return Ok(());
+        }
+        let bottom = top + height as i32 - 1;
+        let y_of = |v: f32| {
+            let fraction = if max > min {
+                ((v - min) / (max - min)).clamp(0.0, 1.0)
+            } else {
+                0.5
+            };
+            bottom - (fraction * (height - 1) as f32).round() as i32
+        };
+        match self.kind {
+            ChartKind::Line => {
+                let x_of = |i: u32| {
+                    if count > 1 {
+                        left + (i as f32 * (width - 1) as f32 / (count - 1) as f32).round() as i32
+                    } else {
+                        left
+                    }
+                };
+                let mut points = self
+                    .values()
+                    .enumerate()
+                    .map(|(i, v)| Point::new(x_of(i as u32), y_of(v)));
+                let Some(mut last) = points.next() else {
+                    return Ok(());
+                };
+                target.draw_iter([Pixel(last, self.color)])?;
+                for point in points {
+                    // the start of each segment is already drawn as the end of the previous one
+                    target.draw_iter(
+                        Line::new(last, point)
+                            .points()
+                            .skip(1)
+                            .map(|p| Pixel(p, self.color)),
+                    )?;
+                    last = point;
+                }
+            }
+            ChartKind::Bar => {
+                for (i, v) in self.values().enumerate() {
+                    let start = i as u32 * width / count;
+                    let end = (i as u32 + 1) * width / count;
+                    // keep a gap between the bars if there is enough space
+                    let bar_width = if end - start > 1 { end - start - 1 } else { 1 };
+                    let y = y_of(v);
+                    target.fill_solid(
+                        &Rectangle::new(
+                            Point::new(left + start as i32, y),
+                            Size::new(bar_width, (bottom - y + 1) as u32),
+                        ),
+                        self.color,
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<I: IntoIterator + Clone, C: PixelColor, S: TextRenderer<Color = C> + Copy> Layoutable<C>
+    for Chart<I, C, S>
+where
+    I::Item: Borrow<f32>,
+{
+    fn size(&self) -> ComponentSize {
+        let labels = self.labels_size();
+        let axes = self.axes_size();
+        ComponentSize {
+            width: ValueRange::fixed(labels.width + axes + MIN_PLOT_SIZE.width).expand_max(),
+            height: ValueRange::fixed((2 * labels.height).max(MIN_PLOT_SIZE.height) + axes)
+                .expand_max(),
+        }
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let target = &mut target.clipped(&position);
+        let Rectangle {
+            top_left: Point { x: left, y: top },
+            size: Size { width, height },
+        } = position;
+        let labels = self.labels_size();
+        let axes = self.axes_size();
+        let plot_left = left + (labels.width + axes) as i32;
+        let plot = Rectangle::new(
+            Point::new(plot_left, top),
+            Size::new(
+                width.saturating_sub(labels.width + axes),
+                height.saturating_sub(axes),
+            ),
+        );
+        if let (Some((max, min)), Some(style)) = (self.label_texts(), self.labels) {
+            let text_style = |baseline| TextStyleBuilder::new().baseline(baseline).build();
+            Text::with_text_style(max, position.top_left, style, text_style(Baseline::Top))
+                .draw(target)?;
+            Text::with_text_style(
+                min,
+                Point::new(left, top + plot.size.height as i32 - 1),
+                style,
+                text_style(Baseline::Bottom),
+            )
+            .draw(target)?;
+        }
+        if let Some(axes_color) = self.axes {
+            let axis_x = plot_left - 1;
+            let axis_y = top + plot.size.height as i32;
+            target.fill_solid(
+                &Rectangle::new(Point::new(axis_x, top), Size::new(1, plot.size.height)),
+                axes_color,
+            )?;
+            target.fill_solid(
+                &Rectangle::new(
+                    Point::new(axis_x, axis_y),
+                    Size::new(plot.size.width + 1, 1),
+                ),
+                axes_color,
+            )?;
+        }
+        self.draw_values(target, plot)
+    }
+}
@@ -0,0 +1,150 @@
+use std::marker::PhantomData;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    pixelcolor::{PixelColor, Rgb888, RgbColor},
+    primitives::Rectangle,
+};
+
+use crate::{
+    dirty::DirtyRegion, draw::ColorMapDrawable, event::InputEvent, inspect::LayoutNode,
+    layoutable::Layoutable, ComponentSize,
+};
+
+///
+/// Draw a element with every color replaced by a mapping function
+///
+/// The mapping may convert into another color type, so elements for monochrome displays can be drawn onto color
+/// displays and vice versa.
+///
+/// # Arguments
+///
+/// * `mapping`: converts the colors of the element into the colors of the display
+/// * `layoutable`: element to be drawn
+///
+/// returns: impl Layoutable<C>+Sized
+///
+/// # Examples
+///
+/// draw a monochrome widget in yellow on a color display
+/// ```
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::{BinaryColor, Rgb565, RgbColor};
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{background, map_color, owned_text, Layoutable};
+/// let widget = background(BinaryColor::On, owned_text(" ", MonoTextStyle::new(&FONT_6X12, BinaryColor::Off)));
+/// let colored = map_color(
+///     |color: BinaryColor| if color.is_on() { Rgb565::YELLOW } else { Rgb565::BLACK },
+///     widget,
+/// );
+/// let mut display = MockDisplay::<Rgb565>::new();
+/// colored.draw_placed(&mut display, Rectangle::new(Point::zero(), Size::new(2, 1))).unwrap();
+/// assert_eq!(display.get_pixel(Point::new(1, 0)), Some(Rgb565::YELLOW));
+/// ```
+/// plain conversion between color types
+/// ```
+/// use embedded_graphics::pixelcolor::{BinaryColor, Rgb565};
+/// use simple_layout::prelude::{map_color, scale, Layoutable};
+/// let converted = map_color(Rgb565::from, scale(0.5, BinaryColor::On));
+/// ```
+pub fn map_color<L, C, CT, F>(mapping: F, layoutable: L) -> impl Layoutable<CT>
+where
+    L: Layoutable<C>,
+    C: PixelColor,
+    CT: PixelColor,
+    F: Fn(C) -> CT,
+{
+    MapColor {
+        mapping,
+        layoutable,
+        p: PhantomData,
+    }
+}
+
+///
+/// Draw a element with inverted colors, e.g. to highlight the selected entry of a menu
+///
+/// # Arguments
+///
+/// * `layoutable`: element to be drawn inverted
+///
+/// returns: impl Layoutable<C>+Sized
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use simple_layout::prelude::{background, expand_horizontal, invert, owned_text};
+/// let selected = invert(background(
+///     BinaryColor::Off,
+///     expand_horizontal(owned_text("Entry", MonoTextStyle::new(&FONT_6X12, BinaryColor::On))),
+/// ));
+/// ```
+pub fn invert<L, C>(layoutable: L) -> impl Layoutable<C>
+where
+    L: Layoutable<C>,
+    C: PixelColor + From<Rgb888> + Into<Rgb888>,
+{
+    map_color(
+        |color: C| {
+            let color: Rgb888 = color.into();
+            Rgb888::new(
+                Rgb888::MAX_R - color.r(),
+                Rgb888::MAX_G - color.g(),
+                Rgb888::MAX_B - color.b(),
+            )
+            .into()
+        },
+        layoutable,
+    )
+}
+
+struct MapColor<L, C, CT, F>
+where
+    L: Layoutable<C>,
+    C: PixelColor,
+    CT: PixelColor,
+    F: Fn(C) -> CT,
+{
+    mapping: F,
+    layoutable: L,
+    p: PhantomData<(C, CT)>,
+}
+
+impl<L, C, CT, F> Layoutable<CT> for MapColor<L, C, CT, F>
+where
+    L: Layoutable<C>,
+    C: PixelColor,
+    CT: PixelColor,
+    F: Fn(C) -> CT,
+{
+    fn size(&self) -> ComponentSize {
+        self.layoutable.size()
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = CT, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        self.layoutable
+            .draw_placed(&mut ColorMapDrawable::new(target, &self.mapping), position)
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(LayoutNode::new(&self.layoutable, position));
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        self.layoutable.collect_dirty(position, dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.layoutable.handle_event(position, event)
+    }
+}
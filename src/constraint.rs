@@ -0,0 +1,336 @@
+use std::marker::PhantomData;
+use std::num::Saturating;
+
+use embedded_graphics::{
+    prelude::{DrawTarget, PixelColor},
+    primitives::Rectangle,
+};
+
+use crate::{
+    dirty::DirtyRegion, event::InputEvent, inspect::LayoutNode, layoutable::Layoutable,
+    ComponentSize, ValueRange,
+};
+
+///
+/// Ensure a minimum size of a element
+///
+/// A larger minimum of the element itself is kept. Preferred and maximum size are raised too if they are below the new
+/// minimum
+///
+/// # Arguments
+///
+/// * `width`: minimum width
+/// * `height`: minimum height
+/// * `layoutable`: element
+///
+/// returns: impl Layoutable<C>+Sized
+///
+/// # Examples
+///
+/// make a button at least 20x20 pixels large
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::Point;
+/// use embedded_graphics::text::Text;
+/// use simple_layout::prelude::{bordered, center, min_size, Layoutable, RoundedLine};
+/// let button = min_size(
+///     20,
+///     20,
+///     bordered(
+///         center(Text::new("+", Point::zero(), MonoTextStyle::new(&FONT_6X12, BinaryColor::On))),
+///         RoundedLine::new(BinaryColor::On),
+///     ),
+/// );
+/// assert_eq!(button.size().min_size().width, 20);
+/// ```
+pub fn min_size<L: Layoutable<C>, C: PixelColor>(
+    width: u32,
+    height: u32,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    ConstraintLayoutable::<_, _, MinConstraint>::new(Some(width), Some(height), layoutable)
+}
+/// Override the minimum width of a element
+pub fn min_width<L: Layoutable<C>, C: PixelColor>(width: u32, layoutable: L) -> impl Layoutable<C> {
+    ConstraintLayoutable::<_, _, MinConstraint>::new(Some(width), None, layoutable)
+}
+/// Override the minimum height of a element
+pub fn min_height<L: Layoutable<C>, C: PixelColor>(
+    height: u32,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    ConstraintLayoutable::<_, _, MinConstraint>::new(None, Some(height), layoutable)
+}
+
+///
+/// Limit the maximum size of a element
+///
+/// A smaller maximum of the element itself is kept. Preferred and minimum size are lowered too if they are above the
+/// new maximum
+///
+/// # Arguments
+///
+/// * `width`: maximum width
+/// * `height`: maximum height
+/// * `layoutable`: element
+///
+/// returns: impl Layoutable<C>+Sized
+///
+/// # Examples
+///
+/// let a scale grow up to 100 pixels
+/// ```
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use simple_layout::prelude::{max_width, scale, Layoutable};
+/// let limited_scale = max_width(100, scale(0.4, BinaryColor::On));
+/// assert_eq!(limited_scale.size().max_size().width, 100);
+/// ```
+pub fn max_size<L: Layoutable<C>, C: PixelColor>(
+    width: u32,
+    height: u32,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    ConstraintLayoutable::<_, _, MaxConstraint>::new(Some(width), Some(height), layoutable)
+}
+/// Override the maximum width of a element
+pub fn max_width<L: Layoutable<C>, C: PixelColor>(width: u32, layoutable: L) -> impl Layoutable<C> {
+    ConstraintLayoutable::<_, _, MaxConstraint>::new(Some(width), None, layoutable)
+}
+/// Override the maximum height of a element
+pub fn max_height<L: Layoutable<C>, C: PixelColor>(
+    height: u32,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    ConstraintLayoutable::<_, _, MaxConstraint>::new(None, Some(height), layoutable)
+}
+
+///
+/// Force a element to a fixed size (minimum, preferred and maximum are set to the same value)
+///
+/// # Arguments
+///
+/// * `width`: fixed width
+/// * `height`: fixed height
+/// * `layoutable`: element
+///
+/// returns: impl Layoutable<C>+Sized
+///
+pub fn fixed_size<L: Layoutable<C>, C: PixelColor>(
+    width: u32,
+    height: u32,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    ConstraintLayoutable::<_, _, FixedConstraint>::new(Some(width), Some(height), layoutable)
+}
+/// Force a element to a fixed width
+pub fn fixed_width<L: Layoutable<C>, C: PixelColor>(
+    width: u32,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    ConstraintLayoutable::<_, _, FixedConstraint>::new(Some(width), None, layoutable)
+}
+/// Force a element to a fixed height
+pub fn fixed_height<L: Layoutable<C>, C: PixelColor>(
+    height: u32,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    ConstraintLayoutable::<_, _, FixedConstraint>::new(None, Some(height), layoutable)
+}
+
+///
+/// Replace the preferred size of a element
+///
+/// Minimum and maximum size are extended if the new preferred size is outside of them
+///
+/// # Arguments
+///
+/// * `width`: preferred width
+/// * `height`: preferred height
+/// * `layoutable`: element
+///
+/// returns: impl Layoutable<C>+Sized
+///
+pub fn preferred_size<L: Layoutable<C>, C: PixelColor>(
+    width: u32,
+    height: u32,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    ConstraintLayoutable::<_, _, PreferredConstraint>::new(Some(width), Some(height), layoutable)
+}
+/// Replace the preferred width of a element
+pub fn preferred_width<L: Layoutable<C>, C: PixelColor>(
+    width: u32,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    ConstraintLayoutable::<_, _, PreferredConstraint>::new(Some(width), None, layoutable)
+}
+/// Replace the preferred height of a element
+pub fn preferred_height<L: Layoutable<C>, C: PixelColor>(
+    height: u32,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    ConstraintLayoutable::<_, _, PreferredConstraint>::new(None, Some(height), layoutable)
+}
+
+trait Constraint {
+    fn constrain_range(
+        range: ValueRange<Saturating<u32>>,
+        value: Saturating<u32>,
+    ) -> ValueRange<Saturating<u32>>;
+}
+
+struct ConstraintLayoutable<L: Layoutable<C>, C: PixelColor, R: Constraint> {
+    layoutable: L,
+    width: Option<u32>,
+    height: Option<u32>,
+    p: PhantomData<C>,
+    p1: PhantomData<R>,
+}
+
+impl<L: Layoutable<C>, C: PixelColor, R: Constraint> ConstraintLayoutable<L, C, R> {
+    fn new(width: Option<u32>, height: Option<u32>, layoutable: L) -> Self {
+        Self {
+            layoutable,
+            width,
+            height,
+            p: PhantomData,
+            p1: PhantomData,
+        }
+    }
+}
+
+impl<L: Layoutable<C>, C: PixelColor, R: Constraint> Layoutable<C>
+    for ConstraintLayoutable<L, C, R>
+{
+    fn size(&self) -> ComponentSize {
+        let ComponentSize { width, height } = self.layoutable.size();
+        ComponentSize {
+            width: self
+                .width
+                .map(|value| R::constrain_range(width, Saturating(value)))
+                .unwrap_or(width),
+            height: self
+                .height
+                .map(|value| R::constrain_range(height, Saturating(value)))
+                .unwrap_or(height),
+        }
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        self.layoutable.draw_placed(target, position)
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(LayoutNode::new(&self.layoutable, position));
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        self.layoutable.collect_dirty(position, dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.layoutable.handle_event(position, event)
+    }
+}
+
+struct MinConstraint;
+
+impl Constraint for MinConstraint {
+    fn constrain_range(
+        range: ValueRange<Saturating<u32>>,
+        value: Saturating<u32>,
+    ) -> ValueRange<Saturating<u32>> {
+        let min_value = range.min_value.max(value);
+        let max_value = range.max_value.max(min_value);
+        ValueRange {
+            preferred_value: range.preferred_value.max(min_value).min(max_value),
+            min_value,
+            max_value,
+        }
+    }
+}
+
+struct MaxConstraint;
+
+impl Constraint for MaxConstraint {
+    fn constrain_range(
+        range: ValueRange<Saturating<u32>>,
+        value: Saturating<u32>,
+    ) -> ValueRange<Saturating<u32>> {
+        let max_value = range.max_value.min(value);
+        let min_value = range.min_value.min(max_value);
+        ValueRange {
+            preferred_value: range.preferred_value.max(min_value).min(max_value),
+            min_value,
+            max_value,
+        }
+    }
+}
+
+struct FixedConstraint;
+
+impl Constraint for FixedConstraint {
+    fn constrain_range(
+        _range: ValueRange<Saturating<u32>>,
+        value: Saturating<u32>,
+    ) -> ValueRange<Saturating<u32>> {
+        ValueRange::fixed(value.0)
+    }
+}
+
+struct PreferredConstraint;
+
+impl Constraint for PreferredConstraint {
+    fn constrain_range(
+        range: ValueRange<Saturating<u32>>,
+        value: Saturating<u32>,
+    ) -> ValueRange<Saturating<u32>> {
+        ValueRange {
+            preferred_value: value,
+            min_value: range.min_value.min(value),
+            max_value: range.max_value.max(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::Saturating;
+
+    use super::{Constraint, MaxConstraint, MinConstraint};
+    use crate::ValueRange;
+
+    fn range(preferred: u32, min: u32, max: u32) -> ValueRange<Saturating<u32>> {
+        ValueRange::new(preferred, min, max)
+    }
+
+    #[test]
+    fn min_keeps_larger_minimum_of_child() {
+        let constrained = MinConstraint::constrain_range(range(30, 20, 40), Saturating(5));
+        assert_eq!(constrained, range(30, 20, 40));
+    }
+
+    #[test]
+    fn min_raises_smaller_ranges() {
+        let constrained = MinConstraint::constrain_range(range(6, 4, 8), Saturating(10));
+        assert_eq!(constrained, range(10, 10, 10));
+    }
+
+    #[test]
+    fn max_keeps_smaller_maximum_of_child() {
+        let constrained = MaxConstraint::constrain_range(range(10, 10, 10), Saturating(20));
+        assert_eq!(constrained, range(10, 10, 10));
+    }
+
+    #[test]
+    fn max_lowers_larger_ranges() {
+        let constrained = MaxConstraint::constrain_range(range(30, 20, u32::MAX), Saturating(10));
+        assert_eq!(constrained, range(10, 10, 10));
+    }
+}
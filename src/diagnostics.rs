@@ -0,0 +1,278 @@
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::ops::DerefMut;
+use std::sync::Mutex;
+
+use embedded_graphics::{
+    draw_target::DrawTarget, geometry::Size, pixelcolor::PixelColor, primitives::Rectangle,
+};
+#[cfg(feature = "log")]
+use log::warn;
+
+use crate::dirty::DirtyRegion;
+use crate::event::InputEvent;
+use crate::inspect::{inspect, LayoutNode};
+use crate::layoutable::Layoutable;
+use crate::ComponentSize;
+
+///
+/// Axis a diagnostic refers to
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+///
+/// Problem found while placing a layout tree onto a given space
+///
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Diagnostic {
+    /// The element got less space than its minimum size, so its content overflows the given area
+    Overflow {
+        path: String,
+        axis: Axis,
+        required: u32,
+        available: u32,
+    },
+    /// The decoration of the element (border, padding) needs more space than available, no space is left for the content
+    Underflow {
+        path: String,
+        axis: Axis,
+        required: u32,
+        available: u32,
+    },
+    /// The element reports a size with a minimum above the maximum or a preferred size outside of the range
+    InvalidRange {
+        path: String,
+        axis: Axis,
+        min: u32,
+        preferred: u32,
+        max: u32,
+    },
+}
+
+impl Diagnostic {
+    /// Path of the offending element, the type names of all elements from the root separated by `/`
+    pub fn path(&self) -> &str {
+        match self {
+            Diagnostic::Overflow { path, .. }
+            | Diagnostic::Underflow { path, .. }
+            | Diagnostic::InvalidRange { path, .. } => path,
+        }
+    }
+    /// Axis the problem occurs on
+    pub fn axis(&self) -> Axis {
+        match self {
+            Diagnostic::Overflow { axis, .. }
+            | Diagnostic::Underflow { axis, .. }
+            | Diagnostic::InvalidRange { axis, .. } => *axis,
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Diagnostic::Overflow {
+                path,
+                axis,
+                required,
+                available,
+            } => write!(
+                f,
+                "{path}: {axis:?} overflow, requires at least {required} but got {available}"
+            ),
+            Diagnostic::Underflow {
+                path,
+                axis,
+                required,
+                available,
+            } => write!(
+                f,
+                "{path}: {axis:?} underflow, decoration requires {required} but got {available}"
+            ),
+            Diagnostic::InvalidRange {
+                path,
+                axis,
+                min,
+                preferred,
+                max,
+            } => write!(
+                f,
+                "{path}: invalid {axis:?} range {min}..{max} with preferred size {preferred}"
+            ),
+        }
+    }
+}
+
+///
+/// Check a layout tree for overflows, underflows and invalid size ranges
+///
+/// An overflow is only reported on the outermost element, the elements within are not checked for overflows again.
+///
+/// # Arguments
+///
+/// * `layoutable`: root element of the tree
+/// * `position`: place to draw the root element onto
+/// * `report`: callback receiving every found problem
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{bordered, diagnose, owned_text, Diagnostic, RoundedLine};
+/// let element = bordered(
+///     owned_text("Too long", MonoTextStyle::new(&FONT_6X12, BinaryColor::On)),
+///     RoundedLine::new(BinaryColor::On),
+/// );
+/// let mut diagnostics = Vec::new();
+/// diagnose(&element, Rectangle::new(Point::zero(), Size::new(3, 16)), |d| diagnostics.push(d));
+/// assert!(matches!(diagnostics[0], Diagnostic::Overflow { required: 51, available: 3, .. }));
+/// assert!(matches!(diagnostics[1], Diagnostic::Underflow { required: 4, available: 3, .. }));
+/// ```
+pub fn diagnose<C: PixelColor, L: Layoutable<C>>(
+    layoutable: &L,
+    position: Rectangle,
+    mut report: impl FnMut(Diagnostic),
+) {
+    let root = inspect(layoutable, position);
+    check_node(&root, root.name().to_string(), false, &mut report);
+}
+
+///
+/// Write all problems of a layout tree as warnings to the log
+///
+/// # Arguments
+///
+/// * `layoutable`: root element of the tree
+/// * `position`: place to draw the root element onto
+///
+#[cfg(feature = "log")]
+pub fn log_diagnostics<C: PixelColor, L: Layoutable<C>>(layoutable: &L, position: Rectangle) {
+    diagnose(layoutable, position, |diagnostic| warn!("{diagnostic}"));
+}
+
+fn check_node(
+    node: &LayoutNode,
+    path: String,
+    overflow_reported: bool,
+    report: &mut impl FnMut(Diagnostic),
+) {
+    let size = node.size();
+    let Size {
+        width: available_width,
+        height: available_height,
+    } = node.position().size;
+    let mut overflow_found = overflow_reported;
+    for (axis, range, available) in [
+        (Axis::Horizontal, size.width(), available_width),
+        (Axis::Vertical, size.height(), available_height),
+    ] {
+        let min = range.min_value();
+        if !range.is_valid() {
+            report(Diagnostic::InvalidRange {
+                path: path.clone(),
+                axis,
+                min,
+                preferred: range.preferred_value(),
+                max: range.max_value(),
+            });
+        }
+        if !overflow_reported && available < min {
+            report(Diagnostic::Overflow {
+                path: path.clone(),
+                axis,
+                required: min,
+                available,
+            });
+            overflow_found = true;
+        }
+    }
+    for (idx, child) in node.children().iter().enumerate() {
+        let insets = child.insets();
+        for (axis, required, available) in [
+            (Axis::Horizontal, insets.width, available_width),
+            (Axis::Vertical, insets.height, available_height),
+        ] {
+            if required > available {
+                report(Diagnostic::Underflow {
+                    path: path.clone(),
+                    axis,
+                    required,
+                    available,
+                });
+            }
+        }
+        check_node(
+            child,
+            format!("{path}/{idx}:{}", child.name()),
+            overflow_found,
+            report,
+        );
+    }
+}
+
+///
+/// Check the layout tree below this element every time it is drawn and report all problems to a callback
+///
+/// # Arguments
+///
+/// * `callback`: Callback receiving the found problems
+/// * `layoutable`: Element to be checked
+///
+/// returns: impl Layoutable<C>+Sized
+///
+pub fn diagnosed<L: Layoutable<C>, C: PixelColor, F: FnMut(Diagnostic)>(
+    callback: F,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    Diagnosed {
+        callback: Mutex::new(callback),
+        layoutable,
+        p: PhantomData,
+    }
+}
+
+struct Diagnosed<L: Layoutable<C>, C: PixelColor, F: FnMut(Diagnostic)> {
+    callback: Mutex<F>,
+    layoutable: L,
+    p: PhantomData<C>,
+}
+
+impl<L: Layoutable<C>, C: PixelColor, F: FnMut(Diagnostic)> Layoutable<C> for Diagnosed<L, C, F> {
+    fn size(&self) -> ComponentSize {
+        self.layoutable.size()
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        if let Ok(mut mutex) = self.callback.try_lock() {
+            diagnose(&self.layoutable, position, mutex.deref_mut());
+        } else {
+            #[cfg(feature = "log")]
+            warn!("Cannot lock callback");
+        }
+        self.layoutable.draw_placed(target, position)
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(LayoutNode::new(&self.layoutable, position));
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        self.layoutable.collect_dirty(position, dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.layoutable.handle_event(position, event)
+    }
+}
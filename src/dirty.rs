@@ -0,0 +1,423 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::DerefMut;
+use std::sync::Mutex;
+
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::Point,
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+};
+#[cfg(feature = "log")]
+use log::warn;
+
+use crate::background::Paint;
+use crate::draw::RegionDrawable;
+use crate::event::InputEvent;
+use crate::inspect::LayoutNode;
+use crate::layoutable::Layoutable;
+use crate::ComponentSize;
+
+///
+/// Set of areas which have to be redrawn
+///
+/// Overlapping or adjacent areas are merged into their bounding box, so every pixel is drawn only once.
+///
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct DirtyRegion {
+    rectangles: Vec<Rectangle>,
+    /// a tracked element changed its size, so its siblings may have moved
+    resized: bool,
+}
+
+impl DirtyRegion {
+    ///
+    /// Mark a area as dirty
+    ///
+    /// # Arguments
+    ///
+    /// * `area`: area to be redrawn
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::prelude::{Point, Size};
+    /// use embedded_graphics::primitives::Rectangle;
+    /// use simple_layout::prelude::DirtyRegion;
+    /// let mut region = DirtyRegion::default();
+    /// region.add(Rectangle::new(Point::new(0, 0), Size::new(10, 10)));
+    /// region.add(Rectangle::new(Point::new(10, 0), Size::new(10, 10)));
+    /// region.add(Rectangle::new(Point::new(0, 50), Size::new(10, 10)));
+    /// assert_eq!(
+    ///     region.rectangles(),
+    ///     &[
+    ///         Rectangle::new(Point::new(0, 0), Size::new(20, 10)),
+    ///         Rectangle::new(Point::new(0, 50), Size::new(10, 10))
+    ///     ]
+    /// );
+    /// ```
+    pub fn add(&mut self, area: Rectangle) {
+        if area.is_zero_sized() {
+            return;
+        }
+        let mut area = area;
+        while let Some(idx) = self
+            .rectangles
+            .iter()
+            .position(|existing| touches(existing, &area))
+        {
+            area = envelope(&self.rectangles.swap_remove(idx), &area);
+        }
+        self.rectangles.push(area);
+    }
+    fn merge(&mut self, other: DirtyRegion) {
+        for area in other.rectangles {
+            self.add(area);
+        }
+        self.resized |= other.resized;
+    }
+    /// All areas to be redrawn
+    pub fn rectangles(&self) -> &[Rectangle] {
+        &self.rectangles
+    }
+    /// true if nothing has to be redrawn
+    pub fn is_empty(&self) -> bool {
+        self.rectangles.is_empty()
+    }
+    /// Smallest rectangle containing all dirty areas
+    pub fn bounding_box(&self) -> Option<Rectangle> {
+        self.rectangles
+            .iter()
+            .copied()
+            .reduce(|a, b| envelope(&a, &b))
+    }
+}
+
+fn touches(a: &Rectangle, b: &Rectangle) -> bool {
+    !a.offset(1).intersection(b).is_zero_sized()
+}
+
+fn envelope(a: &Rectangle, b: &Rectangle) -> Rectangle {
+    match (a.bottom_right(), b.bottom_right()) {
+        (Some(a_bottom_right), Some(b_bottom_right)) => Rectangle::with_corners(
+            Point::new(
+                a.top_left.x.min(b.top_left.x),
+                a.top_left.y.min(b.top_left.y),
+            ),
+            Point::new(
+                a_bottom_right.x.max(b_bottom_right.x),
+                a_bottom_right.y.max(b_bottom_right.y),
+            ),
+        ),
+        (Some(_), None) => *a,
+        (None, _) => *b,
+    }
+}
+
+///
+/// Remembers what a tracked element looked like when it was drawn the last time
+///
+/// Keep one state per tracked element between frames, like the `Option<Rectangle>` of `optional_placement`.
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct DirtyState {
+    drawn: Option<(u64, Rectangle, ComponentSize)>,
+}
+
+impl DirtyState {
+    ///
+    /// Force a redraw of the element on the next frame
+    ///
+    pub fn invalidate(&mut self) {
+        self.drawn = None;
+    }
+}
+
+///
+/// Watch a element for changes of the value it displays
+///
+/// The element is dirty if the hash of the value or its position differs from the last time it was drawn, or if it
+/// was never drawn before. Elements which are not tracked are treated as static content. If the size of the element
+/// changes, its siblings may move, so the whole next tracked element around it (or the whole tree) is redrawn.
+///
+/// # Arguments
+///
+/// * `state`: state of this element kept between frames
+/// * `value`: everything the content of the element depends on
+/// * `layoutable`: element to be watched
+///
+/// returns: impl Layoutable<C>+Sized
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{
+///     redraw_dirty, scale, tracked, vertical_layout, DirtyState,
+/// };
+/// let mut screen_state = DirtyState::default();
+/// let mut scale_state = DirtyState::default();
+/// let mut display = MockDisplay::<BinaryColor>::new();
+/// display.set_allow_overdraw(true);
+/// let area = Rectangle::new(Point::zero(), Size::new(32, 16));
+/// let mut redrawn = Vec::new();
+/// for value in [0.25f32, 0.25, 0.75] {
+///     // the root is tracked with a constant value, so the first frame is drawn completely
+///     let screen = tracked(
+///         &mut screen_state,
+///         (),
+///         vertical_layout(scale(0.5, BinaryColor::On), 0)
+///             .append(tracked(&mut scale_state, value.to_bits(), scale(value, BinaryColor::On)), 0),
+///     );
+///     redrawn.push(redraw_dirty(&screen, &mut display, area, BinaryColor::Off).unwrap());
+/// }
+/// assert_eq!(redrawn[0].rectangles(), &[area]);
+/// assert!(redrawn[1].is_empty());
+/// assert_eq!(redrawn[2].rectangles(), &[Rectangle::new(Point::new(0, 4), Size::new(32, 4))]);
+/// ```
+/// a static unit moves when the tracked value before it becomes wider
+/// ```
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{dirty_regions, horizontal_layout, owned_text, tracked, DirtyState, Layoutable};
+/// let style = MonoTextStyle::new(&FONT_6X12, BinaryColor::On);
+/// let mut screen_state = DirtyState::default();
+/// let mut value_state = DirtyState::default();
+/// let mut display = MockDisplay::<BinaryColor>::new();
+/// display.set_allow_overdraw(true);
+/// let area = Rectangle::new(Point::zero(), Size::new(64, 12));
+/// let mut redrawn = Vec::new();
+/// for value in ["9", "10"] {
+///     let screen = tracked(
+///         &mut screen_state,
+///         (),
+///         horizontal_layout(tracked(&mut value_state, value, owned_text(value, style)), 0)
+///             .append(owned_text("°C", style), 0),
+///     );
+///     redrawn.push(dirty_regions(&screen, area));
+///     screen.draw_placed(&mut display, area).unwrap();
+/// }
+/// // the value got wider, so the whole row around it is redrawn
+/// assert_eq!(redrawn[1].rectangles(), &[area]);
+/// ```
+pub fn tracked<'a, L: Layoutable<C> + 'a, C: PixelColor + 'a, V: Hash>(
+    state: &'a mut DirtyState,
+    value: V,
+    layoutable: L,
+) -> impl Layoutable<C> + 'a {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    Tracked {
+        state: Mutex::new(state),
+        hash: hasher.finish(),
+        layoutable,
+        p: PhantomData,
+    }
+}
+
+struct Tracked<'a, L: Layoutable<C>, C: PixelColor> {
+    state: Mutex<&'a mut DirtyState>,
+    hash: u64,
+    layoutable: L,
+    p: PhantomData<C>,
+}
+
+impl<'a, L: Layoutable<C>, C: PixelColor> Layoutable<C> for Tracked<'a, L, C> {
+    fn size(&self) -> ComponentSize {
+        self.layoutable.size()
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        if let Ok(mut mutex) = self.state.try_lock() {
+            mutex.deref_mut().drawn = Some((self.hash, position, self.layoutable.size()));
+        } else {
+            #[cfg(feature = "log")]
+            warn!("Cannot lock dirty state");
+        }
+        self.layoutable.draw_placed(target, position)
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(LayoutNode::new(&self.layoutable, position));
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        let drawn = self.state.try_lock().ok().and_then(|state| state.drawn);
+        match drawn {
+            Some((_, last_position, last_size)) if last_size != self.layoutable.size() => {
+                dirty.add(last_position);
+                dirty.add(position);
+                dirty.resized = true;
+            }
+            Some((hash, last_position, _)) if hash == self.hash && last_position == position => {
+                let mut inner = DirtyRegion::default();
+                self.layoutable.collect_dirty(position, &mut inner);
+                if inner.resized {
+                    // a element inside changed its size and may have moved its siblings
+                    dirty.add(position);
+                } else {
+                    dirty.merge(inner);
+                }
+            }
+            Some((_, last_position, _)) => {
+                dirty.add(last_position);
+                dirty.add(position);
+            }
+            None => dirty.add(position),
+        }
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.layoutable.handle_event(position, event)
+    }
+}
+
+///
+/// Collect all areas of a layout tree which changed since the last frame
+///
+/// Only tracked elements report changes, the first frame of a tree whose root is not `tracked` has to be drawn
+/// completely with `draw_placed`.
+///
+/// # Arguments
+///
+/// * `layoutable`: root element of the tree
+/// * `position`: place to draw the root element onto
+///
+/// returns: DirtyRegion
+///
+pub fn dirty_regions<C: PixelColor, L: Layoutable<C>>(
+    layoutable: &L,
+    position: Rectangle,
+) -> DirtyRegion {
+    let mut dirty = DirtyRegion::default();
+    layoutable.collect_dirty(position, &mut dirty);
+    if dirty.resized {
+        // no tracked element around the resized one, its siblings can be anywhere
+        dirty = DirtyRegion::default();
+        dirty.add(position);
+    }
+    dirty
+}
+
+///
+/// Clear and redraw only the areas of a layout tree which changed since the last frame
+///
+/// The dirty areas are cleared and the tree is drawn once, masked to the dirty areas, so only the changed pixels are
+/// sent to the display. Like `dirty_regions`, this needs a first frame drawn completely if the root is not `tracked`.
+///
+/// # Arguments
+///
+/// * `layoutable`: root element of the tree
+/// * `target`: Target to draw onto
+/// * `position`: place to draw the root element onto
+/// * `background`: paint to clear the dirty areas with
+///
+/// returns: Result<DirtyRegion, DrawError> the redrawn areas, e.g. to flush only them to the display
+///
+pub fn redraw_dirty<C: PixelColor, L: Layoutable<C>, P: Paint<C>, DrawError>(
+    layoutable: &L,
+    target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+    position: Rectangle,
+    background: P,
+) -> Result<DirtyRegion, DrawError> {
+    let dirty = dirty_regions(layoutable, position);
+    if dirty.is_empty() {
+        return Ok(dirty);
+    }
+    for area in dirty.rectangles() {
+        background.fill(&mut target.clipped(area), *area)?;
+    }
+    layoutable.draw_placed(
+        &mut RegionDrawable::new(target, dirty.rectangles()),
+        position,
+    )?;
+    Ok(dirty)
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics::{
+        geometry::{Point, Size},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::Rectangle,
+    };
+
+    use super::{dirty_regions, tracked, DirtyRegion, DirtyState};
+    use crate::{layoutable::Layoutable, linear::horizontal_layout, scale::scale};
+
+    fn rect(x: i32, y: i32, width: u32, height: u32) -> Rectangle {
+        Rectangle::new(Point::new(x, y), Size::new(width, height))
+    }
+
+    #[test]
+    fn merges_overlapping_and_adjacent_areas() {
+        let mut region = DirtyRegion::default();
+        region.add(rect(0, 0, 10, 10));
+        region.add(rect(5, 5, 10, 10));
+        assert_eq!(region.rectangles(), &[rect(0, 0, 15, 15)]);
+        region.add(rect(15, 0, 5, 5));
+        assert_eq!(region.rectangles(), &[rect(0, 0, 20, 15)]);
+    }
+
+    #[test]
+    fn keeps_distant_areas_apart() {
+        let mut region = DirtyRegion::default();
+        region.add(rect(0, 0, 4, 4));
+        region.add(rect(10, 0, 4, 4));
+        region.add(rect(0, 0, 0, 0));
+        assert_eq!(region.rectangles(), &[rect(0, 0, 4, 4), rect(10, 0, 4, 4)]);
+        assert_eq!(region.bounding_box(), Some(rect(0, 0, 14, 4)));
+    }
+
+    #[test]
+    fn bridging_area_merges_all_touched_areas() {
+        let mut region = DirtyRegion::default();
+        region.add(rect(0, 0, 4, 4));
+        region.add(rect(10, 0, 4, 4));
+        region.add(rect(20, 0, 4, 4));
+        region.add(rect(3, 0, 8, 1));
+        assert_eq!(region.rectangles(), &[rect(20, 0, 4, 4), rect(0, 0, 14, 4)]);
+        region.add(rect(13, 3, 8, 1));
+        assert_eq!(region.rectangles(), &[rect(0, 0, 24, 4)]);
+    }
+
+    #[test]
+    fn reports_only_changed_tracked_elements() {
+        let mut left = DirtyState::default();
+        let mut right = DirtyState::default();
+        let area = rect(0, 0, 40, 4);
+        for (value, expected) in [
+            (0.5, vec![area]),
+            (0.5, vec![]),
+            (0.7, vec![rect(20, 0, 20, 4)]),
+        ] {
+            let row = horizontal_layout(tracked(&mut left, 1, scale(0.5, BinaryColor::On)), 1)
+                .append(
+                    tracked(
+                        &mut right,
+                        (value * 10.0) as u32,
+                        scale(value, BinaryColor::On),
+                    ),
+                    1,
+                );
+            assert_eq!(dirty_regions(&row, area).rectangles(), expected.as_slice());
+            let mut display = MockDisplay::new();
+            display.set_allow_overdraw(true);
+            row.draw_placed(&mut display, area).unwrap();
+        }
+    }
+}
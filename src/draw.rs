@@ -1,9 +1,14 @@
+use std::convert::Infallible;
+use std::marker::PhantomData;
+
 use embedded_graphics::{
-    prelude::{Dimensions, DrawTarget, PixelColor, Point},
-    primitives::Rectangle,
+    prelude::{Dimensions, DrawTarget, PixelColor, Point, Size},
+    primitives::{PointsIter, Rectangle},
     Pixel,
 };
 
+use crate::transform::Transform;
+
 pub(crate) struct OffsetDrawable<'a, Color, Error, Target>
 where
     Target: DrawTarget<Color = Color, Error = Error>,
@@ -57,3 +62,367 @@ where
         )
     }
 }
+
+/// Draws only the pixels inside any of the given areas
+pub(crate) struct RegionDrawable<'a, Color, Error, Target>
+where
+    Target: DrawTarget<Color = Color, Error = Error>,
+    Color: PixelColor,
+{
+    target: &'a mut Target,
+    region: &'a [Rectangle],
+}
+
+impl<'a, Color, Error, Target> RegionDrawable<'a, Color, Error, Target>
+where
+    Target: DrawTarget<Color = Color, Error = Error>,
+    Color: PixelColor,
+{
+    pub fn new(target: &'a mut Target, region: &'a [Rectangle]) -> Self {
+        Self { target, region }
+    }
+}
+
+impl<'a, Color, Error, Target> Dimensions for RegionDrawable<'a, Color, Error, Target>
+where
+    Target: DrawTarget<Color = Color, Error = Error>,
+    Color: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<'a, Color, Error, Target> DrawTarget for RegionDrawable<'a, Color, Error, Target>
+where
+    Target: DrawTarget<Color = Color, Error = Error>,
+    Color: PixelColor,
+{
+    type Color = Color;
+    type Error = Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let region = self.region;
+        self.target.draw_iter(
+            pixels
+                .into_iter()
+                .filter(|Pixel(p, _)| region.iter().any(|area| area.contains(*p))),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        // the areas of a dirty region don't overlap, so every pixel is filled once
+        for part in self.region {
+            let intersection = part.intersection(area);
+            if !intersection.is_zero_sized() {
+                self.target.fill_solid(&intersection, color)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) struct ColorMapDrawable<'a, Color, TargetColor, Error, Target, F>
+where
+    Target: DrawTarget<Color = TargetColor, Error = Error>,
+    Color: PixelColor,
+    TargetColor: PixelColor,
+    F: Fn(Color) -> TargetColor,
+{
+    target: &'a mut Target,
+    mapping: &'a F,
+    p: PhantomData<Color>,
+}
+
+impl<'a, Color, TargetColor, Error, Target, F>
+    ColorMapDrawable<'a, Color, TargetColor, Error, Target, F>
+where
+    Target: DrawTarget<Color = TargetColor, Error = Error>,
+    Color: PixelColor,
+    TargetColor: PixelColor,
+    F: Fn(Color) -> TargetColor,
+{
+    pub fn new(target: &'a mut Target, mapping: &'a F) -> Self {
+        Self {
+            target,
+            mapping,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<'a, Color, TargetColor, Error, Target, F> Dimensions
+    for ColorMapDrawable<'a, Color, TargetColor, Error, Target, F>
+where
+    Target: DrawTarget<Color = TargetColor, Error = Error>,
+    Color: PixelColor,
+    TargetColor: PixelColor,
+    F: Fn(Color) -> TargetColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<'a, Color, TargetColor, Error, Target, F> DrawTarget
+    for ColorMapDrawable<'a, Color, TargetColor, Error, Target, F>
+where
+    Target: DrawTarget<Color = TargetColor, Error = Error>,
+    Color: PixelColor,
+    TargetColor: PixelColor,
+    F: Fn(Color) -> TargetColor,
+{
+    type Color = Color;
+    type Error = Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let mapping = self.mapping;
+        self.target.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel::<Self::Color>(p, c)| Pixel(p, mapping(c))),
+        )
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let mapping = self.mapping;
+        self.target
+            .fill_contiguous(area, colors.into_iter().map(mapping))
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.target.fill_solid(area, (self.mapping)(color))
+    }
+}
+
+pub(crate) struct TransformDrawable<'a, Color, Error, Target>
+where
+    Target: DrawTarget<Color = Color, Error = Error>,
+    Color: PixelColor,
+{
+    target: &'a mut Target,
+    transform: Transform,
+    position: Rectangle,
+}
+
+impl<'a, Color, Error, Target> TransformDrawable<'a, Color, Error, Target>
+where
+    Target: DrawTarget<Color = Color, Error = Error>,
+    Color: PixelColor,
+{
+    pub fn new(target: &'a mut Target, transform: Transform, position: Rectangle) -> Self {
+        Self {
+            target,
+            transform,
+            position,
+        }
+    }
+}
+
+impl<'a, Color, Error, Target> Dimensions for TransformDrawable<'a, Color, Error, Target>
+where
+    Target: DrawTarget<Color = Color, Error = Error>,
+    Color: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(
+            Point::zero(),
+            self.transform.transform_size(self.position.size),
+        )
+    }
+}
+
+impl<'a, Color, Error, Target> DrawTarget for TransformDrawable<'a, Color, Error, Target>
+where
+    Target: DrawTarget<Color = Color, Error = Error>,
+    Color: PixelColor,
+{
+    type Color = Color;
+    type Error = Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let transform = self.transform;
+        let position = self.position;
+        self.target.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel::<Self::Color>(p, c)| Pixel(transform.transform_point(p, position), c)),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let Some(bottom_right) = area.bottom_right() else {
+            return Ok(());
+        };
+        let corner1 = self.transform.transform_point(area.top_left, self.position);
+        let corner2 = self.transform.transform_point(bottom_right, self.position);
+        self.target
+            .fill_solid(&Rectangle::with_corners(corner1, corner2), color)
+    }
+}
+
+pub(crate) struct ZoomDrawable<'a, Color, Error, Target>
+where
+    Target: DrawTarget<Color = Color, Error = Error>,
+    Color: PixelColor,
+{
+    target: &'a mut Target,
+    factor: u32,
+    position: Rectangle,
+}
+
+impl<'a, Color, Error, Target> ZoomDrawable<'a, Color, Error, Target>
+where
+    Target: DrawTarget<Color = Color, Error = Error>,
+    Color: PixelColor,
+{
+    pub fn new(target: &'a mut Target, factor: u32, position: Rectangle) -> Self {
+        Self {
+            target,
+            factor,
+            position,
+        }
+    }
+    fn zoom_area(&self, area: &Rectangle) -> Rectangle {
+        Rectangle::new(
+            self.position.top_left + area.top_left * self.factor as i32,
+            area.size * self.factor,
+        )
+    }
+}
+
+impl<'a, Color, Error, Target> Dimensions for ZoomDrawable<'a, Color, Error, Target>
+where
+    Target: DrawTarget<Color = Color, Error = Error>,
+    Color: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(Point::zero(), self.position.size / self.factor)
+    }
+}
+
+impl<'a, Color, Error, Target> DrawTarget for ZoomDrawable<'a, Color, Error, Target>
+where
+    Target: DrawTarget<Color = Color, Error = Error>,
+    Color: PixelColor,
+{
+    type Color = Color;
+    type Error = Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let factor = self.factor;
+        let top_left = self.position.top_left;
+        self.target
+            .draw_iter(pixels.into_iter().flat_map(|Pixel::<Self::Color>(p, c)| {
+                Rectangle::new(top_left + p * factor as i32, Size::new(factor, factor))
+                    .points()
+                    .map(move |p| Pixel(p, c))
+            }))
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.zoom_area(area);
+        self.target.fill_solid(&area, color)
+    }
+}
+
+///
+/// Object safe access to a draw target, so elements drawing onto any target can be stored as trait objects
+///
+trait ErasedTarget<Color: PixelColor> {
+    fn draw_pixels(&mut self, pixels: &mut dyn Iterator<Item = Pixel<Color>>);
+    fn fill_area(&mut self, area: &Rectangle, color: Color);
+    fn area(&self) -> Rectangle;
+}
+
+///
+/// Keeps the first error of the wrapped target and skips all drawing afterwards
+///
+struct ErrorKeepingTarget<'a, Target: DrawTarget> {
+    target: &'a mut Target,
+    error: Option<Target::Error>,
+}
+
+impl<'a, Target: DrawTarget> ErasedTarget<Target::Color> for ErrorKeepingTarget<'a, Target> {
+    fn draw_pixels(&mut self, pixels: &mut dyn Iterator<Item = Pixel<Target::Color>>) {
+        if self.error.is_none() {
+            self.error = self.target.draw_iter(pixels).err();
+        }
+    }
+
+    fn fill_area(&mut self, area: &Rectangle, color: Target::Color) {
+        if self.error.is_none() {
+            self.error = self.target.fill_solid(area, color).err();
+        }
+    }
+
+    fn area(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+///
+/// Draw target of a fixed type forwarding to a target of any type, errors are reported by `draw_erased`
+///
+pub(crate) struct ErasedDrawable<'a, Color: PixelColor> {
+    target: &'a mut dyn ErasedTarget<Color>,
+}
+
+impl<'a, Color: PixelColor> Dimensions for ErasedDrawable<'a, Color> {
+    fn bounding_box(&self) -> Rectangle {
+        self.target.area()
+    }
+}
+
+impl<'a, Color: PixelColor> DrawTarget for ErasedDrawable<'a, Color> {
+    type Color = Color;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.target.draw_pixels(&mut pixels.into_iter());
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.target.fill_area(area, color);
+        Ok(())
+    }
+}
+
+///
+/// Draw onto a target through a `ErasedDrawable`, returns the first error of the target
+///
+pub(crate) fn draw_erased<Target: DrawTarget, R>(
+    target: &mut Target,
+    draw: impl FnOnce(&mut ErasedDrawable<Target::Color>) -> R,
+) -> Result<R, Target::Error> {
+    let mut keeping = ErrorKeepingTarget {
+        target,
+        error: None,
+    };
+    let result = draw(&mut ErasedDrawable {
+        target: &mut keeping,
+    });
+    match keeping.error {
+        Some(error) => Err(error),
+        None => Ok(result),
+    }
+}
@@ -0,0 +1,227 @@
+use std::marker::PhantomData;
+use std::ops::DerefMut;
+use std::sync::Mutex;
+
+use embedded_graphics::{
+    draw_target::DrawTarget, geometry::Point, pixelcolor::PixelColor, primitives::Rectangle,
+};
+#[cfg(feature = "log")]
+use log::warn;
+
+use crate::dirty::DirtyRegion;
+use crate::focus::Navigation;
+use crate::inspect::LayoutNode;
+use crate::layoutable::Layoutable;
+use crate::ComponentSize;
+
+///
+/// Key of a keypad or keyboard
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    /// move to the next element (tab)
+    Next,
+    /// move to the previous element (shift tab)
+    Previous,
+    /// activate the focused element
+    Enter,
+    /// leave the current screen or cancel
+    Back,
+    /// any other key
+    Char(char),
+}
+
+///
+/// Input from a touchscreen, mouse, keys or a rotary encoder
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum InputEvent {
+    /// touch or mouse button pressed at a point
+    Press(Point),
+    /// touch or mouse button released at a point
+    Release(Point),
+    /// pointer moved to a point
+    Move(Point),
+    /// key pressed
+    Key(Key),
+    /// rotary encoder turned by a count of steps, positive values clockwise
+    Encoder(i32),
+}
+
+impl InputEvent {
+    /// Point of pointer events
+    pub fn point(&self) -> Option<Point> {
+        match self {
+            InputEvent::Press(p) | InputEvent::Release(p) | InputEvent::Move(p) => Some(*p),
+            InputEvent::Key(_) | InputEvent::Encoder(_) => None,
+        }
+    }
+    /// true if the event is not a pointer event or the pointer is within the given area
+    pub fn hits(&self, area: Rectangle) -> bool {
+        self.point().map(|p| area.contains(p)).unwrap_or(true)
+    }
+    ///
+    /// Transform the point of a pointer event, other events are returned unchanged
+    ///
+    pub fn map_point(self, mapping: impl FnOnce(Point) -> Point) -> Self {
+        match self {
+            InputEvent::Press(p) => InputEvent::Press(mapping(p)),
+            InputEvent::Release(p) => InputEvent::Release(mapping(p)),
+            InputEvent::Move(p) => InputEvent::Move(mapping(p)),
+            other => other,
+        }
+    }
+    ///
+    /// Focus movement requested by this event
+    ///
+    /// Arrow keys move in their direction, the encoder moves to the next or previous element and enter activates.
+    ///
+    pub fn navigation(&self) -> Option<Navigation> {
+        match self {
+            InputEvent::Key(Key::Up) => Some(Navigation::Up),
+            InputEvent::Key(Key::Down) => Some(Navigation::Down),
+            InputEvent::Key(Key::Left) => Some(Navigation::Left),
+            InputEvent::Key(Key::Right) => Some(Navigation::Right),
+            InputEvent::Key(Key::Next) => Some(Navigation::Next),
+            InputEvent::Key(Key::Previous) => Some(Navigation::Previous),
+            InputEvent::Key(Key::Enter) => Some(Navigation::Activate),
+            InputEvent::Encoder(steps) if *steps > 0 => Some(Navigation::Next),
+            InputEvent::Encoder(steps) if *steps < 0 => Some(Navigation::Previous),
+            _ => None,
+        }
+    }
+}
+
+///
+/// Route a event through a layout tree
+///
+/// Pointer events reach the elements below the pointer, key and encoder events reach the focused element and all
+/// elements outside of `focusable` elements. The innermost handler is asked first.
+///
+/// # Arguments
+///
+/// * `layoutable`: root element of the tree
+/// * `position`: place the root element was drawn onto
+/// * `event`: event to be delivered
+///
+/// returns: bool true if a handler consumed the event
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{dispatch, horizontal_layout, on_event, scale, InputEvent};
+/// let value = Cell::new(0.5);
+/// let area = Rectangle::new(Point::zero(), Size::new(100, 10));
+/// let screen = horizontal_layout(
+///     on_event(
+///         |event, _| {
+///             matches!(event, InputEvent::Release(_)) && {
+///                 value.set(value.get() - 0.1);
+///                 true
+///             }
+///         },
+///         scale(value.get(), BinaryColor::On),
+///     ),
+///     1,
+/// )
+/// .append(
+///     on_event(
+///         |event, _| {
+///             matches!(event, InputEvent::Release(_)) && {
+///                 value.set(value.get() + 0.1);
+///                 true
+///             }
+///         },
+///         scale(value.get(), BinaryColor::On),
+///     ),
+///     1,
+/// );
+/// assert!(dispatch(&screen, area, InputEvent::Release(Point::new(80, 5))));
+/// assert_eq!(value.get(), 0.6);
+/// assert!(!dispatch(&screen, area, InputEvent::Release(Point::new(80, 50))));
+/// ```
+pub fn dispatch<C: PixelColor, L: Layoutable<C>>(
+    layoutable: &L,
+    position: Rectangle,
+    event: InputEvent,
+) -> bool {
+    layoutable.handle_event(position, event)
+}
+
+///
+/// Register a handler for input events on a element
+///
+/// The handler is only called if no element within consumed the event and pointer events only if the pointer is
+/// within the element.
+///
+/// # Arguments
+///
+/// * `handler`: Callback receiving the event and the place of the element, returns true if the event is consumed
+/// * `layoutable`: Element receiving the events
+///
+/// returns: impl Layoutable<C>+Sized
+///
+pub fn on_event<L: Layoutable<C>, C: PixelColor, F: FnMut(InputEvent, Rectangle) -> bool>(
+    handler: F,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    EventHandler {
+        handler: Mutex::new(handler),
+        layoutable,
+        p: PhantomData,
+    }
+}
+
+struct EventHandler<L: Layoutable<C>, C: PixelColor, F: FnMut(InputEvent, Rectangle) -> bool> {
+    handler: Mutex<F>,
+    layoutable: L,
+    p: PhantomData<C>,
+}
+
+impl<L: Layoutable<C>, C: PixelColor, F: FnMut(InputEvent, Rectangle) -> bool> Layoutable<C>
+    for EventHandler<L, C, F>
+{
+    fn size(&self) -> ComponentSize {
+        self.layoutable.size()
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        self.layoutable.draw_placed(target, position)
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(LayoutNode::new(&self.layoutable, position));
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        self.layoutable.collect_dirty(position, dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        if self.layoutable.handle_event(position, event) {
+            return true;
+        }
+        if !event.hits(position) {
+            return false;
+        }
+        if let Ok(mut mutex) = self.handler.try_lock() {
+            (mutex.deref_mut())(event, position)
+        } else {
+            #[cfg(feature = "log")]
+            warn!("Cannot lock event handler");
+            false
+        }
+    }
+}
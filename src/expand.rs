@@ -5,7 +5,10 @@ use embedded_graphics::{
     primitives::Rectangle,
 };
 
-use crate::{layoutable::Layoutable, ComponentSize};
+use crate::{
+    dirty::DirtyRegion, event::InputEvent, inspect::LayoutNode, layoutable::Layoutable,
+    ComponentSize,
+};
 
 ///
 /// remove the maximum size constraints (set width and height to u32::MAX)
@@ -66,6 +69,18 @@ impl<L: Layoutable<C>, C: PixelColor, E: Expander> Layoutable<C> for ExpandLayou
     ) -> Result<(), DrawError> {
         self.layoutable.draw_placed(target, position)
     }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(LayoutNode::new(&self.layoutable, position));
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        self.layoutable.collect_dirty(position, dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.layoutable.handle_event(position, event)
+    }
 }
 
 struct AreaExpander;
@@ -0,0 +1,566 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::ops::DerefMut;
+use std::sync::Mutex;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::Point,
+    pixelcolor::PixelColor,
+    prelude::Primitive,
+    primitives::{PrimitiveStyleBuilder, Rectangle, StrokeAlignment},
+    Drawable,
+};
+#[cfg(feature = "log")]
+use log::warn;
+
+use crate::border::{Decorator, Insets};
+use crate::dirty::DirtyRegion;
+use crate::event::{InputEvent, Key};
+use crate::inspect::LayoutNode;
+use crate::layoutable::Layoutable;
+use crate::transform::area_on_target;
+use crate::ComponentSize;
+
+///
+/// Request to move the focus or to activate the focused element, e.g. from buttons or a rotary encoder
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Navigation {
+    /// next element in reading order (left to right, top to bottom), wraps around at the end
+    Next,
+    /// previous element in reading order, wraps around at the start
+    Previous,
+    /// nearest element above the focused one
+    Up,
+    /// nearest element below the focused one
+    Down,
+    /// nearest element left of the focused one
+    Left,
+    /// nearest element right of the focused one
+    Right,
+    /// activate the focused element
+    Activate,
+}
+
+///
+/// Keeps track of the focused element and of the places of all focusable elements
+///
+/// The places are updated every time a `focusable` element is drawn, so the navigation always follows the last
+/// drawn layout. Places inside of rotated, mirrored or zoomed elements are mapped onto the display, so the directions
+/// match what the user sees. Keep the manager between frames like the state of the application and call `next_frame` before
+/// drawing a new frame, so elements which are not drawn anymore cannot be focused.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{
+///     focusable, horizontal_layout, scale, FocusManager, FocusRing, Layoutable, Navigation,
+/// };
+/// let manager = FocusManager::default();
+/// let row = horizontal_layout(focusable(&manager, "volume", FocusRing::new(BinaryColor::On), scale(0.3, BinaryColor::On)), 1)
+///     .append(focusable(&manager, "balance", FocusRing::new(BinaryColor::On), scale(0.5, BinaryColor::On)), 1);
+/// let mut display = MockDisplay::new();
+/// display.set_allow_overdraw(true);
+/// row.draw_placed(&mut display, Rectangle::new(Point::zero(), Size::new(60, 10))).unwrap();
+///
+/// assert_eq!(manager.navigate(Navigation::Next), None);
+/// assert_eq!(manager.focused(), Some("volume"));
+/// assert_eq!(manager.navigate(Navigation::Right), None);
+/// assert_eq!(manager.focused(), Some("balance"));
+/// assert_eq!(manager.navigate(Navigation::Activate), Some("balance"));
+/// ```
+#[derive(Debug)]
+pub struct FocusManager<K: Copy + Eq> {
+    state: RefCell<FocusState<K>>,
+}
+
+#[derive(Debug)]
+struct FocusState<K: Copy + Eq> {
+    focused: Option<K>,
+    frame: u64,
+    targets: Vec<FocusTarget<K>>,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct FocusTarget<K: Copy + Eq> {
+    key: K,
+    /// place as the element got it, to detect changes
+    position: Rectangle,
+    /// place on the real target, inside of transformed elements this differs from `position`
+    area: Rectangle,
+    ring_drawn: bool,
+    frame: u64,
+}
+
+impl<K: Copy + Eq> Default for FocusManager<K> {
+    fn default() -> Self {
+        Self {
+            state: RefCell::new(FocusState {
+                focused: None,
+                frame: 0,
+                targets: Vec::new(),
+            }),
+        }
+    }
+}
+
+impl<K: Copy + Eq> FocusManager<K> {
+    /// Key of the focused element
+    pub fn focused(&self) -> Option<K> {
+        self.state.borrow().focused
+    }
+    /// true if the element with the given key is focused
+    pub fn is_focused(&self, key: K) -> bool {
+        self.focused() == Some(key)
+    }
+    ///
+    /// Move the focus to a element
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: key of the element to be focused, None removes the focus
+    ///
+    pub fn focus(&self, key: Option<K>) {
+        self.state.borrow_mut().focused = key;
+    }
+    ///
+    /// Start a new frame, all elements which were not drawn since the last call are forgotten
+    ///
+    /// If the focused element is forgotten, nothing is focused anymore.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::mock_display::MockDisplay;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use embedded_graphics::prelude::{Point, Size};
+    /// use embedded_graphics::primitives::Rectangle;
+    /// use simple_layout::prelude::{focusable, scale, FocusManager, FocusRing, Layoutable, Navigation};
+    /// let manager = FocusManager::default();
+    /// let area = Rectangle::new(Point::zero(), Size::new(30, 10));
+    /// for key in ["volume", "balance"] {
+    ///     manager.next_frame();
+    ///     let mut display = MockDisplay::<BinaryColor>::new();
+    ///     focusable(&manager, key, FocusRing::new(BinaryColor::On), scale(0.3, BinaryColor::On))
+    ///         .draw_placed(&mut display, area)
+    ///         .unwrap();
+    /// }
+    /// manager.next_frame();
+    /// // only the element of the last frame is left
+    /// manager.navigate(Navigation::Next);
+    /// assert_eq!(manager.focused(), Some("balance"));
+    /// manager.navigate(Navigation::Next);
+    /// assert_eq!(manager.focused(), Some("balance"));
+    /// ```
+    pub fn next_frame(&self) {
+        let mut state = self.state.borrow_mut();
+        let frame = state.frame;
+        state.targets.retain(|target| target.frame == frame);
+        if let Some(focused) = state.focused {
+            if !state.targets.iter().any(|target| target.key == focused) {
+                state.focused = None;
+            }
+        }
+        // elements drawn in the last frame stay known until they are missing in the new frame
+        state.frame = frame.wrapping_add(1);
+    }
+    ///
+    /// Forget all known elements and the focus, e.g. when switching to another screen
+    ///
+    pub fn clear(&self) {
+        let mut state = self.state.borrow_mut();
+        state.focused = None;
+        state.targets.clear();
+    }
+    ///
+    /// Move the focus or activate the focused element
+    ///
+    /// If nothing is focused, any movement focuses the first element (the last one on `Previous`). `Activate` only reports
+    /// the focused element, to trigger it dispatch `InputEvent::Key(Key::Enter)` through the layout tree instead, which
+    /// reaches the focused element and its `on_activate` callback.
+    ///
+    /// # Arguments
+    ///
+    /// * `navigation`: requested movement
+    ///
+    /// returns: Option<K> the key of the focused element on `Navigation::Activate`
+    ///
+    pub fn navigate(&self, navigation: Navigation) -> Option<K> {
+        let mut state = self.state.borrow_mut();
+        let mut order = state.targets.clone();
+        order.sort_by_key(|target| (target.area.top_left.y, target.area.top_left.x));
+        let current = state
+            .focused
+            .and_then(|key| order.iter().position(|target| target.key == key));
+        let next = match (navigation, current) {
+            (Navigation::Activate, _) => return current.map(|idx| order[idx].key),
+            (_, None) if order.is_empty() => None,
+            (Navigation::Previous, None) => Some(order.len() - 1),
+            (_, None) => Some(0),
+            (Navigation::Next, Some(idx)) => Some((idx + 1) % order.len()),
+            (Navigation::Previous, Some(idx)) => Some((idx + order.len() - 1) % order.len()),
+            (direction, Some(idx)) => nearest_in_direction(&order, idx, direction).or(Some(idx)),
+        };
+        if let Some(idx) = next {
+            state.focused = Some(order[idx].key);
+        }
+        None
+    }
+    pub(crate) fn register(&self, key: K, position: Rectangle, ring_drawn: bool) {
+        let mut state = self.state.borrow_mut();
+        let target = FocusTarget {
+            key,
+            position,
+            area: area_on_target(position),
+            ring_drawn,
+            frame: state.frame,
+        };
+        if let Some(existing) = state.targets.iter_mut().find(|t| t.key == key) {
+            *existing = target;
+        } else {
+            state.targets.push(target);
+        }
+    }
+    fn is_unchanged(&self, key: K, position: Rectangle) -> bool {
+        let state = self.state.borrow();
+        let focused = state.focused == Some(key);
+        state
+            .targets
+            .iter()
+            .any(|t| t.key == key && t.position == position && t.ring_drawn == focused)
+    }
+}
+
+fn nearest_in_direction<K: Copy + Eq>(
+    order: &[FocusTarget<K>],
+    current: usize,
+    direction: Navigation,
+) -> Option<usize> {
+    let Point { x: cx, y: cy } = order[current].area.center();
+    order
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| *idx != current)
+        .filter_map(|(idx, target)| {
+            let Point { x, y } = target.area.center();
+            let (along, across) = match direction {
+                Navigation::Up => (cy - y, x - cx),
+                Navigation::Down => (y - cy, x - cx),
+                Navigation::Left => (cx - x, y - cy),
+                Navigation::Right => (x - cx, y - cy),
+                _ => return None,
+            };
+            // prefer elements in line with the focused one over closer ones besides
+            (along > 0).then_some((along + 2 * across.abs(), idx))
+        })
+        .min()
+        .map(|(_, idx)| idx)
+}
+
+///
+/// Frame drawn around the focused element
+///
+#[derive(Copy, Clone, Debug)]
+pub struct FocusRing<C: PixelColor> {
+    color: C,
+    gap: u32,
+}
+
+impl<C: PixelColor> FocusRing<C> {
+    ///
+    /// 1 pixel frame with 1 pixel space to the element
+    ///
+    /// # Arguments
+    ///
+    /// * `color`: Color of the frame
+    ///
+    /// returns: FocusRing<C>
+    ///
+    pub fn new(color: C) -> Self {
+        Self { color, gap: 1 }
+    }
+    ///
+    /// Change the space between frame and element
+    ///
+    pub fn with_gap(self, gap: u32) -> Self {
+        Self { gap, ..self }
+    }
+}
+
+impl<C: PixelColor> Decorator<C> for FocusRing<C> {
+    fn insets(&self) -> Insets {
+        Insets::uniform(self.gap.saturating_add(1))
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        position
+            .into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(self.color)
+                    .stroke_width(1)
+                    .stroke_alignment(StrokeAlignment::Inside)
+                    .build(),
+            )
+            .draw(target)
+    }
+}
+
+///
+/// Make a element reachable by navigation
+///
+/// The space of the focus ring is always reserved, so the layout does not change when the focus moves.
+///
+/// # Arguments
+///
+/// * `manager`: focus manager of the screen
+/// * `key`: unique key of this element
+/// * `ring`: decoration drawn around the element while it is focused
+/// * `layoutable`: element to be focused
+///
+/// returns: Focusable<K, D, L, C, fn()>
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{
+///     dispatch, focusable, scale, FocusManager, FocusRing, InputEvent, Key, Layoutable, Navigation,
+/// };
+/// let manager = FocusManager::default();
+/// let muted = Cell::new(false);
+/// let area = Rectangle::new(Point::zero(), Size::new(30, 10));
+/// let volume = focusable(&manager, "volume", FocusRing::new(BinaryColor::On), scale(0.3, BinaryColor::On))
+///     .on_activate(|| muted.set(!muted.get()));
+/// let mut display = MockDisplay::<BinaryColor>::new();
+/// display.set_allow_overdraw(true);
+/// volume.draw_placed(&mut display, area).unwrap();
+/// // enter only reaches the focused element
+/// assert!(!dispatch(&volume, area, InputEvent::Key(Key::Enter)));
+/// manager.navigate(Navigation::Next);
+/// assert!(dispatch(&volume, area, InputEvent::Key(Key::Enter)));
+/// assert!(muted.get());
+/// ```
+pub fn focusable<'a, K: Copy + Eq, D: Decorator<C>, L: Layoutable<C>, C: PixelColor>(
+    manager: &'a FocusManager<K>,
+    key: K,
+    ring: D,
+    layoutable: L,
+) -> Focusable<'a, K, D, L, C, fn()> {
+    Focusable {
+        manager,
+        key,
+        ring,
+        layoutable,
+        on_activate: None,
+        p: PhantomData,
+    }
+}
+
+///
+/// Element reachable by navigation, see `focusable`
+///
+pub struct Focusable<'a, K: Copy + Eq, D: Decorator<C>, L: Layoutable<C>, C: PixelColor, F: FnMut()>
+{
+    manager: &'a FocusManager<K>,
+    key: K,
+    ring: D,
+    layoutable: L,
+    on_activate: Option<Mutex<F>>,
+    p: PhantomData<C>,
+}
+
+impl<'a, K: Copy + Eq, D: Decorator<C>, L: Layoutable<C>, C: PixelColor, F: FnMut()>
+    Focusable<'a, K, D, L, C, F>
+{
+    ///
+    /// Call a function when enter is pressed while this element is focused and the element itself does not use the key
+    ///
+    pub fn on_activate<F2: FnMut()>(self, on_activate: F2) -> Focusable<'a, K, D, L, C, F2> {
+        Focusable {
+            manager: self.manager,
+            key: self.key,
+            ring: self.ring,
+            layoutable: self.layoutable,
+            on_activate: Some(Mutex::new(on_activate)),
+            p: PhantomData,
+        }
+    }
+    fn inner_position(&self, position: Rectangle) -> Rectangle {
+        self.ring.insets().shrink(position)
+    }
+    fn activate(&self) -> bool {
+        let Some(on_activate) = &self.on_activate else {
+            return false;
+        };
+        if let Ok(mut mutex) = on_activate.try_lock() {
+            (mutex.deref_mut())();
+        } else {
+            #[cfg(feature = "log")]
+            warn!("Cannot lock activation callback");
+        }
+        true
+    }
+}
+
+impl<'a, K: Copy + Eq, D: Decorator<C>, L: Layoutable<C>, C: PixelColor, F: FnMut()> Layoutable<C>
+    for Focusable<'a, K, D, L, C, F>
+{
+    fn size(&self) -> ComponentSize {
+        self.layoutable.size() + self.ring.insets().total()
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let focused = self.manager.is_focused(self.key);
+        self.manager.register(self.key, position, focused);
+        if focused {
+            self.ring.draw_placed(target, position)?;
+        }
+        self.layoutable
+            .draw_placed(target, self.inner_position(position))
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(
+            LayoutNode::new(&self.layoutable, self.inner_position(position))
+                .with_insets(self.ring.insets().total()),
+        );
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        if self.manager.is_unchanged(self.key, position) {
+            self.layoutable
+                .collect_dirty(self.inner_position(position), dirty)
+        } else {
+            dirty.add(position)
+        }
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        match event {
+            InputEvent::Press(point) if position.contains(point) => {
+                self.manager.focus(Some(self.key))
+            }
+            InputEvent::Key(_) | InputEvent::Encoder(_) if !self.manager.is_focused(self.key) => {
+                return false
+            }
+            _ => {}
+        }
+        self.layoutable
+            .handle_event(self.inner_position(position), event)
+            || (event == InputEvent::Key(Key::Enter) && self.activate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use embedded_graphics::prelude::{Point, Size};
+    use embedded_graphics::primitives::Rectangle;
+
+    use super::{focusable, FocusManager, FocusRing, Navigation};
+    use crate::layoutable::Layoutable;
+    use crate::linear::horizontal_layout;
+    use crate::scale::scale;
+    use crate::transform::rotate270;
+
+    fn rect(x: i32, y: i32) -> Rectangle {
+        Rectangle::new(Point::new(x, y), Size::new(10, 10))
+    }
+
+    #[test]
+    fn next_follows_reading_order_and_wraps() {
+        let manager = FocusManager::default();
+        // registered in another order than they are shown
+        manager.register("bottom", rect(0, 20), false);
+        manager.register("top right", rect(20, 0), false);
+        manager.register("top left", rect(0, 0), false);
+        let mut order = Vec::new();
+        for _ in 0..4 {
+            manager.navigate(Navigation::Next);
+            order.push(manager.focused().unwrap());
+        }
+        assert_eq!(order, ["top left", "top right", "bottom", "top left"]);
+        manager.navigate(Navigation::Previous);
+        assert_eq!(manager.focused(), Some("bottom"));
+        assert_eq!(manager.navigate(Navigation::Activate), Some("bottom"));
+    }
+
+    #[test]
+    fn direction_prefers_elements_in_line() {
+        let manager = FocusManager::default();
+        manager.register("start", rect(0, 20), false);
+        manager.register("diagonal", rect(12, 8), false);
+        manager.register("in line", rect(30, 20), false);
+        manager.register("above", rect(0, 0), false);
+        manager.focus(Some("start"));
+        manager.navigate(Navigation::Right);
+        assert_eq!(manager.focused(), Some("in line"));
+        // nothing further right, the focus stays
+        manager.navigate(Navigation::Right);
+        assert_eq!(manager.focused(), Some("in line"));
+        manager.focus(Some("start"));
+        manager.navigate(Navigation::Up);
+        assert_eq!(manager.focused(), Some("above"));
+    }
+
+    #[test]
+    fn navigation_follows_rotated_container() {
+        let manager = FocusManager::default();
+        // the row runs from the bottom to the top of the display
+        let column = rotate270(
+            horizontal_layout(
+                focusable(
+                    &manager,
+                    "volume",
+                    FocusRing::new(BinaryColor::On),
+                    scale(0.3, BinaryColor::On),
+                ),
+                1,
+            )
+            .append(
+                focusable(
+                    &manager,
+                    "balance",
+                    FocusRing::new(BinaryColor::On),
+                    scale(0.5, BinaryColor::On),
+                ),
+                1,
+            ),
+        );
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        column
+            .draw_placed(
+                &mut display,
+                Rectangle::new(Point::zero(), Size::new(10, 60)),
+            )
+            .unwrap();
+
+        manager.navigate(Navigation::Next);
+        assert_eq!(manager.focused(), Some("balance"));
+        manager.navigate(Navigation::Right);
+        assert_eq!(manager.focused(), Some("balance"));
+        manager.navigate(Navigation::Down);
+        assert_eq!(manager.focused(), Some("volume"));
+        manager.navigate(Navigation::Up);
+        assert_eq!(manager.focused(), Some("balance"));
+    }
+}
@@ -0,0 +1,330 @@
+use std::f32::consts::FRAC_1_SQRT_2;
+use std::marker::PhantomData;
+
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{Angle, AngleUnit, Point, Size},
+    pixelcolor::PixelColor,
+    prelude::Primitive,
+    primitives::{Arc, Line, PrimitiveStyle, Rectangle},
+    Drawable,
+};
+
+use crate::dirty::DirtyRegion;
+use crate::event::InputEvent;
+use crate::inspect::LayoutNode;
+use crate::layoutable::Layoutable;
+use crate::{ComponentSize, ValueRange};
+
+/// length of the tick marks inside the arc
+const TICK_LENGTH: u32 = 2;
+
+///
+/// Round gauge showing a value as a filled portion of an arc with another element in its center
+///
+/// The gauge is always drawn as a circle, if the available space is not square the circle is centered within it.
+/// By default the arc starts at the bottom left and runs clockwise over 270° to the bottom right.
+///
+/// # Arguments
+///
+/// * `value`: value to be represented, between 0.0 and 1.0 unless another range is configured
+/// * `color`: color of the arc, the ticks and the needle
+/// * `content`: element centered inside the arc, e.g. the value as text
+///
+/// returns: Gauge<impl Layoutable<C>, C>
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X9;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Dimensions, Point, Size};
+/// use simple_layout::prelude::{gauge, inspect, owned_text, Layoutable};
+/// let temperature = 21.5;
+/// let thermostat = gauge(
+///     temperature,
+///     BinaryColor::On,
+///     owned_text(format!("{temperature:.1}"), MonoTextStyle::new(&FONT_6X9, BinaryColor::On)),
+/// )
+/// .with_range(15.0, 30.0)
+/// .with_ticks(7)
+/// .with_needle();
+/// // the gauge is a circle around the text
+/// let size = thermostat.size().preferred_size();
+/// assert_eq!(size.width, size.height);
+/// let mut display = MockDisplay::<BinaryColor>::new();
+/// display.set_allow_overdraw(true);
+/// let area = display.bounding_box();
+/// thermostat.draw_placed(&mut display, area).unwrap();
+/// // the text is centered inside the arc
+/// let tree = inspect(&thermostat, area);
+/// assert_eq!(tree.children()[0].position().center(), area.center());
+/// ```
+pub fn gauge<L: Layoutable<C>, C: PixelColor>(value: f32, color: C, content: L) -> Gauge<L, C> {
+    Gauge {
+        value,
+        min: 0.0,
+        max: 1.0,
+        color,
+        track_color: None,
+        needle: false,
+        start: 135.0.deg(),
+        sweep: 270.0.deg(),
+        thickness: 3,
+        ticks: 0,
+        content,
+        p: PhantomData,
+    }
+}
+
+///
+/// Arc showing a value without anything in its center, see `gauge`
+///
+/// # Arguments
+///
+/// * `value`: value to be represented, between 0.0 and 1.0 unless another range is configured
+/// * `color`: color of the arc
+///
+/// returns: Gauge<impl Layoutable<C>, C>
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use simple_layout::prelude::{arc_indicator, Layoutable};
+/// let humidity = arc_indicator(0.6, BinaryColor::On).with_thickness(2);
+/// assert_eq!(humidity.size().preferred_size().width, 6);
+/// ```
+pub fn arc_indicator<C: PixelColor>(value: f32, color: C) -> Gauge<impl Layoutable<C>, C> {
+    gauge(value, color, NoContent)
+}
+
+struct NoContent;
+
+impl<C: PixelColor> Layoutable<C> for NoContent {
+    fn size(&self) -> ComponentSize {
+        ComponentSize::fixed_size(0, 0)
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        _target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        _position: Rectangle,
+    ) -> Result<(), DrawError> {
+        Ok(())
+    }
+}
+
+///
+/// Round element showing a value on an arc, see `gauge` and `arc_indicator`
+///
+pub struct Gauge<L: Layoutable<C>, C: PixelColor> {
+    value: f32,
+    min: f32,
+    max: f32,
+    color: C,
+    track_color: Option<C>,
+    needle: bool,
+    start: Angle,
+    sweep: Angle,
+    thickness: u32,
+    ticks: u32,
+    content: L,
+    p: PhantomData<C>,
+}
+
+impl<L: Layoutable<C>, C: PixelColor> Gauge<L, C> {
+    ///
+    /// Change the range of the value, default is 0.0 - 1.0
+    ///
+    pub fn with_range(self, min: f32, max: f32) -> Self {
+        Self { min, max, ..self }
+    }
+    ///
+    /// Change the part of the circle covered by the arc
+    ///
+    /// # Arguments
+    ///
+    /// * `start`: angle of the minimum, 0° is on the right side
+    /// * `sweep`: angle from the minimum to the maximum, positive values run clockwise
+    ///
+    /// returns: Gauge<L, C>
+    ///
+    pub fn with_angles(self, start: Angle, sweep: Angle) -> Self {
+        Self {
+            start,
+            sweep,
+            ..self
+        }
+    }
+    ///
+    /// Change the thickness of the filled arc, default is 3 pixel
+    ///
+    pub fn with_thickness(self, thickness: u32) -> Self {
+        Self {
+            thickness: thickness.max(1),
+            ..self
+        }
+    }
+    ///
+    /// Draw the whole arc as a thin line, so the empty part stays visible
+    ///
+    pub fn with_track(self, track_color: C) -> Self {
+        Self {
+            track_color: Some(track_color),
+            ..self
+        }
+    }
+    ///
+    /// Draw evenly distributed tick marks inside the arc from the minimum to the maximum
+    ///
+    /// # Arguments
+    ///
+    /// * `ticks`: count of tick marks, 0 draws none
+    ///
+    /// returns: Gauge<L, C>
+    ///
+    pub fn with_ticks(self, ticks: u32) -> Self {
+        Self { ticks, ..self }
+    }
+    ///
+    /// Draw a needle from the center pointing to the value
+    ///
+    pub fn with_needle(self) -> Self {
+        Self {
+            needle: true,
+            ..self
+        }
+    }
+    fn fraction(&self) -> f32 {
+        if self.max > self.min {
+            ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+    /// space between the outer edge and the inner slot
+    fn ring_width(&self) -> u32 {
+        let ticks = if self.ticks > 0 { TICK_LENGTH + 1 } else { 0 };
+        self.thickness + ticks + 1
+    }
+    fn diameter_for(&self, content: Size) -> u32 {
+        let side = content.width.max(content.height) as f32;
+        let slot_radius = (side * FRAC_1_SQRT_2).ceil() as u32;
+        2 * (slot_radius + self.ring_width())
+    }
+    /// largest square centered within the available space
+    fn circle_area(&self, position: Rectangle) -> Rectangle {
+        let Size { width, height } = position.size;
+        let diameter = width.min(height);
+        Rectangle::with_center(position.center(), Size::new(diameter, diameter))
+    }
+    /// largest square fitting into the arc
+    fn slot_area(&self, position: Rectangle) -> Rectangle {
+        let circle = self.circle_area(position);
+        let radius = (circle.size.width / 2).saturating_sub(self.ring_width());
+        let side = (2.0 * radius as f32 * FRAC_1_SQRT_2) as u32;
+        Rectangle::with_center(circle.center(), Size::new(side, side))
+    }
+    fn part_of_sweep(&self, fraction: f32) -> Angle {
+        Angle::from_radians(self.sweep.to_radians() * fraction)
+    }
+    fn point_at(center: (f32, f32), radius: f32, angle: Angle) -> Point {
+        let angle = angle.to_radians();
+        Point::new(
+            (center.0 + radius * angle.cos()).round() as i32,
+            (center.1 + radius * angle.sin()).round() as i32,
+        )
+    }
+}
+
+impl<L: Layoutable<C>, C: PixelColor> Layoutable<C> for Gauge<L, C> {
+    fn size(&self) -> ComponentSize {
+        let content_size = self.content.size();
+        let preferred = self.diameter_for(content_size.preferred_size());
+        let min = self.diameter_for(content_size.min_size());
+        let diameter = ValueRange::new(preferred, min, u32::MAX);
+        ComponentSize {
+            width: diameter,
+            height: diameter,
+        }
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let circle = self.circle_area(position);
+        let diameter = circle.size.width;
+        if diameter > 2 * self.thickness {
+            let target = &mut target.clipped(&circle);
+            let half = self.thickness / 2;
+            let arc = Arc::new(
+                circle.top_left + Point::new(half as i32, half as i32),
+                diameter - self.thickness,
+                self.start,
+                self.sweep,
+            );
+            if let Some(track_color) = self.track_color {
+                arc.into_styled(PrimitiveStyle::with_stroke(track_color, 1))
+                    .draw(target)?;
+            }
+            let fraction = self.fraction();
+            if fraction > 0.0 {
+                Arc {
+                    angle_sweep: self.part_of_sweep(fraction),
+                    ..arc
+                }
+                .into_styled(PrimitiveStyle::with_stroke(self.color, self.thickness))
+                .draw(target)?;
+            }
+            let Point { x, y } = circle.top_left;
+            let center = (
+                x as f32 + (diameter - 1) as f32 / 2.0,
+                y as f32 + (diameter - 1) as f32 / 2.0,
+            );
+            let inner_radius = (diameter / 2 - self.thickness) as f32 - 1.0;
+            for tick in 0..self.ticks {
+                let angle = if self.ticks > 1 {
+                    self.start + self.part_of_sweep(tick as f32 / (self.ticks - 1) as f32)
+                } else {
+                    self.start + self.part_of_sweep(0.5)
+                };
+                Line::new(
+                    Self::point_at(center, inner_radius, angle),
+                    Self::point_at(center, inner_radius - TICK_LENGTH as f32 + 1.0, angle),
+                )
+                .into_styled(PrimitiveStyle::with_stroke(self.color, 1))
+                .draw(target)?;
+            }
+            self.content.draw_placed(target, self.slot_area(position))?;
+            if self.needle {
+                let angle = self.start + self.part_of_sweep(fraction);
+                Line::new(
+                    Self::point_at(center, 0.0, angle),
+                    Self::point_at(center, inner_radius, angle),
+                )
+                .into_styled(PrimitiveStyle::with_stroke(self.color, 1))
+                .draw(target)?;
+            }
+            Ok(())
+        } else {
+            self.content.draw_placed(target, self.slot_area(position))
+        }
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(LayoutNode::new(&self.content, self.slot_area(position)));
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        self.content.collect_dirty(self.slot_area(position), dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.content.handle_event(self.slot_area(position), event)
+    }
+}
@@ -0,0 +1,208 @@
+use std::fmt::{Display, Formatter};
+use std::num::Saturating;
+
+use embedded_graphics::{
+    geometry::{Point, Size},
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+};
+#[cfg(feature = "log")]
+use log::debug;
+
+use crate::layoutable::Layoutable;
+use crate::{ComponentSize, ValueRange};
+
+///
+/// Resolved placement of a single element within a layouted tree
+///
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct LayoutNode {
+    name: &'static str,
+    position: Rectangle,
+    size: ComponentSize,
+    weight: Option<u32>,
+    insets: Size,
+    children: Vec<LayoutNode>,
+}
+
+impl LayoutNode {
+    ///
+    /// Resolve a element and all of its children placed onto a given position
+    ///
+    /// # Arguments
+    ///
+    /// * `layoutable`: element to inspect
+    /// * `position`: place the element would be drawn onto
+    ///
+    /// returns: LayoutNode
+    ///
+    pub fn new<C: PixelColor, L: Layoutable<C>>(layoutable: &L, position: Rectangle) -> Self {
+        let mut children = Vec::new();
+        layoutable.inspect_children(position, &mut children);
+        Self {
+            name: short_type_name(std::any::type_name::<L>()),
+            position,
+            size: layoutable.size(),
+            weight: None,
+            insets: Size::zero(),
+            children,
+        }
+    }
+    ///
+    /// Attach the weight this element has within its linear layout
+    ///
+    pub fn with_weight(self, weight: u32) -> Self {
+        Self {
+            weight: Some(weight),
+            ..self
+        }
+    }
+    ///
+    /// Attach the space the parent element reserves around this element for its own decoration
+    ///
+    pub fn with_insets(self, insets: Size) -> Self {
+        Self { insets, ..self }
+    }
+    ///
+    /// Move this element and all of its children onto other places, e.g. from the coordinates of a transformed
+    /// child back onto the screen
+    ///
+    pub(crate) fn map_positions(self, mapping: &impl Fn(Rectangle) -> Rectangle) -> Self {
+        Self {
+            position: mapping(self.position),
+            children: self
+                .children
+                .into_iter()
+                .map(|child| child.map_positions(mapping))
+                .collect(),
+            ..self
+        }
+    }
+    /// Name of the type of the element (without module path and generic parameters)
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+    /// Place the element is drawn onto
+    pub fn position(&self) -> Rectangle {
+        self.position
+    }
+    /// Size constraints reported by the element
+    pub fn size(&self) -> ComponentSize {
+        self.size
+    }
+    /// Weight of the element, if it is placed by a linear layout
+    pub fn weight(&self) -> Option<u32> {
+        self.weight
+    }
+    /// Space reserved by the parent element around this element
+    pub fn insets(&self) -> Size {
+        self.insets
+    }
+    /// Resolved children of the element
+    pub fn children(&self) -> &[LayoutNode] {
+        &self.children
+    }
+
+    fn fmt_indented(&self, f: &mut Formatter<'_>, depth: usize) -> std::fmt::Result {
+        let Rectangle {
+            top_left: Point { x, y },
+            size: Size { width, height },
+        } = self.position;
+        write!(
+            f,
+            "{:indent$}{} @ ({x}, {y}) {width}x{height}, width: {}, height: {}",
+            "",
+            self.name,
+            DisplayRange(&self.size.width),
+            DisplayRange(&self.size.height),
+            indent = depth * 2
+        )?;
+        if let Some(weight) = self.weight {
+            write!(f, ", weight: {weight}")?;
+        }
+        writeln!(f)?;
+        for child in self.children.iter() {
+            child.fmt_indented(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for LayoutNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+struct DisplayRange<'a>(&'a ValueRange<Saturating<u32>>);
+
+impl Display for DisplayRange<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ValueRange {
+            preferred_value: Saturating(preferred),
+            min_value: Saturating(min),
+            max_value: Saturating(max),
+        } = *self.0;
+        if max == u32::MAX {
+            write!(f, "{min}..inf (preferred {preferred})")
+        } else {
+            write!(f, "{min}..{max} (preferred {preferred})")
+        }
+    }
+}
+
+fn short_type_name(name: &'static str) -> &'static str {
+    let without_generics = name.split('<').next().unwrap_or(name);
+    without_generics
+        .rsplit("::")
+        .next()
+        .unwrap_or(without_generics)
+}
+
+///
+/// Resolve the full layout tree of a element as it would be drawn onto a given position
+///
+/// # Arguments
+///
+/// * `layoutable`: root element of the tree
+/// * `position`: place to draw the root element onto
+///
+/// returns: LayoutNode
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{center, expand, inspect, owned_text, scale, vertical_layout};
+/// let layout = vertical_layout(
+///     expand(center(owned_text("21.3°C", MonoTextStyle::new(&FONT_6X12, BinaryColor::On)))),
+///     1,
+/// )
+/// .append(scale(0.5, BinaryColor::On), 0);
+/// let tree = inspect(&layout, Rectangle::new(Point::zero(), Size::new(64, 32)));
+/// assert_eq!(tree.children().len(), 2);
+/// assert_eq!(tree.children()[1].position().size, Size::new(64, 4));
+/// println!("{tree}");
+/// ```
+pub fn inspect<C: PixelColor, L: Layoutable<C>>(layoutable: &L, position: Rectangle) -> LayoutNode {
+    LayoutNode::new(layoutable, position)
+}
+
+///
+/// Write the resolved layout tree of a element as debug messages to the log
+///
+/// # Arguments
+///
+/// * `layoutable`: root element of the tree
+/// * `position`: place to draw the root element onto
+///
+#[cfg(feature = "log")]
+pub fn log_layout<C: PixelColor, L: Layoutable<C>>(layoutable: &L, position: Rectangle) {
+    for line in inspect(layoutable, position).to_string().lines() {
+        debug!("{line}");
+    }
+}
@@ -4,12 +4,14 @@ use embedded_graphics::{
     geometry::Size,
     image::Image,
     prelude::{Dimensions, DrawTarget, ImageDrawable, PixelColor, Point},
-    primitives::Rectangle,
+    primitives::{PrimitiveStyle, Rectangle, Styled, StyledDimensions, StyledDrawable},
     text::{renderer::TextRenderer, Text, TextStyle, TextStyleBuilder},
     Drawable,
 };
 
-use crate::{draw::OffsetDrawable, ComponentSize};
+use crate::{
+    dirty::DirtyRegion, draw::OffsetDrawable, event::InputEvent, inspect::LayoutNode, ComponentSize,
+};
 
 ///
 /// Defines any Layoutable element (anything that can be layouted)
@@ -34,6 +36,43 @@ pub trait Layoutable<Color: PixelColor> {
         target: &mut impl DrawTarget<Color = Color, Error = DrawError>,
         position: Rectangle,
     ) -> Result<(), DrawError>;
+    ///
+    /// Collects the children of this element as they would be placed for a given position
+    ///
+    /// Leaf elements keep the default implementation, containers report every child they would draw
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: place this element would be drawn onto
+    /// * `children`: collection to append the resolved children to
+    ///
+    fn inspect_children(&self, _position: Rectangle, _children: &mut Vec<LayoutNode>) {}
+    ///
+    /// Collects the areas of this element whose content changed since it was drawn the last time
+    ///
+    /// Leaf elements keep the default implementation (never changing), containers forward to every child they would draw
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: place this element would be drawn onto
+    /// * `dirty`: region to add the changed areas to
+    ///
+    fn collect_dirty(&self, _position: Rectangle, _dirty: &mut DirtyRegion) {}
+    ///
+    /// Delivers a input event to this element and its children
+    ///
+    /// Leaf elements keep the default implementation (ignoring all events), containers forward to their children
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: place this element was drawn onto
+    /// * `event`: event to be delivered
+    ///
+    /// returns: bool true if the event was consumed
+    ///
+    fn handle_event(&self, _position: Rectangle, _event: InputEvent) -> bool {
+        false
+    }
 }
 
 ///
@@ -170,6 +209,50 @@ impl<'a, C: PixelColor, T: ImageDrawable<Color = C>> Layoutable<C> for Image<'a,
     }
 }
 
+///
+/// Render styled primitives like `Circle`, `Rectangle`, `Line` or `Triangle` as Layoutable
+///
+/// The size is fixed to the bounding box including the stroke, use `stretch` to fill the placed area instead.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Primitive, Size};
+/// use embedded_graphics::primitives::{Circle, PrimitiveStyle};
+/// use simple_layout::prelude::{horizontal_layout, Layoutable};
+/// let led = Circle::new(Point::zero(), 5).into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+/// let status = horizontal_layout(led, 0).append(
+///     Circle::new(Point::zero(), 5).into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1)),
+///     0,
+/// );
+/// assert_eq!(status.size().preferred_size(), Size::new(10, 5));
+/// ```
+impl<
+        C: PixelColor,
+        T: StyledDimensions<PrimitiveStyle<C>> + StyledDrawable<PrimitiveStyle<C>, Color = C>,
+    > Layoutable<C> for Styled<T, PrimitiveStyle<C>>
+{
+    fn size(&self) -> ComponentSize {
+        let Rectangle {
+            size: Size { width, height },
+            ..
+        } = self.bounding_box();
+        ComponentSize::fixed_size(width, height)
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let Rectangle { top_left, .. } = self.bounding_box();
+        let offset = position.top_left - top_left;
+        self.draw(&mut OffsetDrawable::new(target, offset))?;
+        Ok(())
+    }
+}
+
 ///
 /// Render a `Optional<impl Layoutable> as Layoutable`
 ///
@@ -191,4 +274,52 @@ impl<C: PixelColor, L: Layoutable<C>> Layoutable<C> for Option<L> {
             Some(l) => l.draw_placed(target, position),
         }
     }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        if let Some(l) = self {
+            children.push(LayoutNode::new(l, position));
+        }
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        if let Some(l) = self {
+            l.collect_dirty(position, dirty);
+        }
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        match self {
+            None => false,
+            Some(l) => l.handle_event(position, event),
+        }
+    }
+}
+
+///
+/// Use a borrowed element, e.g. to wrap a element owned by another one only while drawing it
+///
+impl<C: PixelColor, L: Layoutable<C>> Layoutable<C> for &L {
+    fn size(&self) -> ComponentSize {
+        (*self).size()
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        (*self).draw_placed(target, position)
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        (*self).inspect_children(position, children)
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        (*self).collect_dirty(position, dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        (*self).handle_event(position, event)
+    }
 }
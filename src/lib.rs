@@ -1,28 +1,83 @@
+use std::fmt::{Display, Formatter};
 use std::num::Saturating;
-use std::ops::{Add, AddAssign, Range, Sub};
+use std::ops::{Add, AddAssign, Mul, Range, Sub};
+
+use embedded_graphics::geometry::Size;
+
+use crate::diagnostics::Axis;
 
 mod draw;
 
 mod align;
+mod aspect;
+mod background;
 mod border;
+mod buffer;
+mod button;
+mod cache;
+mod chart;
+mod color;
+mod constraint;
+mod diagnostics;
+mod dirty;
+mod event;
 mod expand;
+mod focus;
+mod gauge;
+mod inspect;
 mod layoutable;
 mod linear;
 mod padding;
 mod placement;
 mod scale;
+mod slider;
+mod stretch;
+mod theme;
+mod transform;
 
 pub mod prelude {
     pub use crate::{
-        align::{center, east, north, south, west},
-        border::{bordered, DashedLine, RoundedLine},
+        align::{center, east, north, south, west, Align},
+        aspect::{aspect_ratio, aspect_ratio_aligned},
+        background::{background, DitherPattern, LinearGradient, Paint},
+        border::{
+            bordered, filled, shadowed, BevelLine, DashedLine, Decorator, DoubleLine, Insets,
+            RoundedLine, RoundedRectangleLine, SideLine, TitledLine,
+        },
+        buffer::DoubleBuffer,
+        button::{button, text_button, Button, ButtonLook, ButtonState, ButtonStyle},
+        cache::{cached, Cached},
+        chart::{bar_chart, line_chart, sparkline, Chart},
+        color::{invert, map_color},
+        constraint::{
+            fixed_height, fixed_size, fixed_width, max_height, max_size, max_width, min_height,
+            min_size, min_width, preferred_height, preferred_size, preferred_width,
+        },
+        diagnostics::{diagnose, diagnosed, Axis, Diagnostic},
+        dirty::{dirty_regions, redraw_dirty, tracked, DirtyRegion, DirtyState},
+        event::{dispatch, on_event, InputEvent, Key},
         expand::{expand, expand_horizontal, expand_vertical},
+        focus::{focusable, FocusManager, FocusRing, Focusable, Navigation},
+        gauge::{arc_indicator, gauge, Gauge},
+        inspect::{inspect, LayoutNode},
         layoutable::{owned_text, Layoutable},
         linear::{horizontal_layout, vertical_layout},
         padding::padding,
         placement::{callback_placement, optional_placement},
-        scale::scale,
+        scale::{progress_bar, scale, BarFill, ProgressBar},
+        slider::{
+            slider, stepper, vertical_slider, Slider, SliderState, Stepper, StepperState,
+            TrackStyle,
+        },
+        stretch::{stretch, Stretchable},
+        theme::{
+            current_theme, set_default_theme, themed_background, themed_bordered, themed_scale,
+            themed_text, with_theme, Role, Theme, ThemeTextStyle,
+        },
+        transform::{mirror_h, mirror_v, rotate180, rotate270, rotate90, zoom},
     };
+    #[cfg(feature = "log")]
+    pub use crate::{diagnostics::log_diagnostics, inspect::log_layout};
 }
 
 ///
@@ -34,6 +89,9 @@ pub struct ComponentSize {
     height: ValueRange<Saturating<u32>>,
 }
 
+///
+/// Preferred, minimum and maximum value of a single dimension of a ComponentSize
+///
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct ValueRange<V> {
     preferred_value: V,
@@ -91,8 +149,26 @@ where
     }
 }
 
+impl<V> Mul<V> for ValueRange<V>
+where
+    V: Mul<V, Output = V> + Clone,
+{
+    type Output = ValueRange<V>;
+
+    fn mul(self, rhs: V) -> Self::Output {
+        ValueRange {
+            preferred_value: self.preferred_value * rhs.clone(),
+            min_value: self.min_value * rhs.clone(),
+            max_value: self.max_value * rhs,
+        }
+    }
+}
+
 impl<V: PartialOrd + Clone> ValueRange<V> {
-    fn expand(&mut self, rhs: &Self) {
+    ///
+    /// Extend all values of this range to be at least as large as the values of another range
+    ///
+    pub fn expand(&mut self, rhs: &Self) {
         if self.preferred_value < rhs.preferred_value {
             self.preferred_value = rhs.preferred_value.clone();
         }
@@ -114,7 +190,10 @@ impl<V: AddAssign> AddAssign for ValueRange<V> {
 }
 
 impl<V: Clone> ValueRange<Saturating<V>> {
-    fn fixed(value: V) -> Self {
+    ///
+    /// Create a range allowing only a single value
+    ///
+    pub fn fixed(value: V) -> Self {
         Self {
             preferred_value: Saturating(value.clone()),
             min_value: Saturating(value.clone()),
@@ -124,15 +203,98 @@ impl<V: Clone> ValueRange<Saturating<V>> {
 }
 
 impl ValueRange<Saturating<u32>> {
-    fn expand_max(&self) -> Self {
+    ///
+    /// Create a range without checking its consistency
+    ///
+    /// # Arguments
+    ///
+    /// * `preferred_value`: optimal value
+    /// * `min_value`: smallest allowed value
+    /// * `max_value`: largest allowed value
+    ///
+    /// returns: ValueRange<Saturating<u32>>
+    ///
+    pub fn new(preferred_value: u32, min_value: u32, max_value: u32) -> Self {
+        Self {
+            preferred_value: Saturating(preferred_value),
+            min_value: Saturating(min_value),
+            max_value: Saturating(max_value),
+        }
+    }
+    ///
+    /// Remove the upper limit of the range (set the maximum to u32::MAX)
+    ///
+    pub fn expand_max(&self) -> Self {
         Self {
             preferred_value: self.preferred_value,
             min_value: self.min_value,
             max_value: Saturating(u32::MAX),
         }
     }
+    /// optimal value
+    pub fn preferred_value(&self) -> u32 {
+        self.preferred_value.0
+    }
+    /// smallest allowed value
+    pub fn min_value(&self) -> u32 {
+        self.min_value.0
+    }
+    /// largest allowed value
+    pub fn max_value(&self) -> u32 {
+        self.max_value.0
+    }
+    ///
+    /// Check if the minimum is not above the maximum and the preferred value is between them
+    ///
+    pub fn is_valid(&self) -> bool {
+        self.min_value <= self.preferred_value && self.preferred_value <= self.max_value
+    }
+    fn check(self, axis: Axis) -> Result<Self, InvalidRangeError> {
+        if self.is_valid() {
+            Ok(self)
+        } else {
+            Err(InvalidRangeError {
+                axis,
+                min: self.min_value.0,
+                preferred: self.preferred_value.0,
+                max: self.max_value.0,
+            })
+        }
+    }
+}
+
+///
+/// Error of a ComponentSize with a inconsistent range
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct InvalidRangeError {
+    /// dimension with the inconsistent range
+    pub axis: Axis,
+    /// given minimum value
+    pub min: u32,
+    /// given preferred value
+    pub preferred: u32,
+    /// given maximum value
+    pub max: u32,
+}
+
+impl Display for InvalidRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let InvalidRangeError {
+            axis,
+            min,
+            preferred,
+            max,
+        } = self;
+        write!(
+            f,
+            "invalid {axis:?} range {min}..{max} with preferred size {preferred}"
+        )
+    }
 }
 
+impl std::error::Error for InvalidRangeError {}
+
 impl ComponentSize {
     ///
     /// Create a fixed sized constraint
@@ -160,6 +322,8 @@ impl ComponentSize {
     ///
     /// Defines a fully customizeable component size
     ///
+    /// The ranges are not checked, use [`ComponentSize::try_new`] to reject inconsistent values
+    ///
     /// # Arguments
     ///
     /// * `preferred_width`: optimal width of the component
@@ -188,4 +352,202 @@ impl ComponentSize {
             },
         }
     }
+    ///
+    /// Defines a fully customizeable component size and checks the consistency of the ranges
+    ///
+    /// # Arguments
+    ///
+    /// * `preferred_width`: optimal width of the component
+    /// * `preferred_height`: optimal height of the component
+    /// * `width_range`: minimum and maximum width
+    /// * `height_range`: minimum and maximum height
+    ///
+    /// returns: Result<ComponentSize, InvalidRangeError>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_layout::ComponentSize;
+    /// assert!(ComponentSize::try_new(10, 8, 5..20, 8..8).is_ok());
+    /// // preferred width is above the maximum
+    /// assert!(ComponentSize::try_new(30, 8, 5..20, 8..8).is_err());
+    /// ```
+    pub fn try_new(
+        preferred_width: u32,
+        preferred_height: u32,
+        width_range: Range<u32>,
+        height_range: Range<u32>,
+    ) -> Result<Self, InvalidRangeError> {
+        Self::new(preferred_width, preferred_height, width_range, height_range).validate()
+    }
+    ///
+    /// Combine a width and height range to a size
+    ///
+    pub fn from_ranges(
+        width: ValueRange<Saturating<u32>>,
+        height: ValueRange<Saturating<u32>>,
+    ) -> Self {
+        Self { width, height }
+    }
+    /// Range of the width
+    pub fn width(&self) -> ValueRange<Saturating<u32>> {
+        self.width
+    }
+    /// Range of the height
+    pub fn height(&self) -> ValueRange<Saturating<u32>> {
+        self.height
+    }
+    /// Preferred width and height
+    pub fn preferred_size(&self) -> Size {
+        Size::new(self.width.preferred_value.0, self.height.preferred_value.0)
+    }
+    /// Minimum width and height
+    pub fn min_size(&self) -> Size {
+        Size::new(self.width.min_value.0, self.height.min_value.0)
+    }
+    /// Maximum width and height
+    pub fn max_size(&self) -> Size {
+        Size::new(self.width.max_value.0, self.height.max_value.0)
+    }
+    ///
+    /// Check the consistency of both ranges
+    ///
+    /// returns: the unchanged size or the first inconsistent range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_layout::ComponentSize;
+    /// let size = ComponentSize::fixed_size(10, 8).with_min_width(12);
+    /// assert!(size.validate().is_err());
+    /// ```
+    pub fn validate(self) -> Result<Self, InvalidRangeError> {
+        self.width.check(Axis::Horizontal)?;
+        self.height.check(Axis::Vertical)?;
+        Ok(self)
+    }
+    /// Replace the minimum width
+    pub fn with_min_width(self, min_width: u32) -> Self {
+        let mut result = self;
+        result.width.min_value = Saturating(min_width);
+        result
+    }
+    /// Replace the preferred width
+    pub fn with_preferred_width(self, preferred_width: u32) -> Self {
+        let mut result = self;
+        result.width.preferred_value = Saturating(preferred_width);
+        result
+    }
+    /// Replace the maximum width
+    pub fn with_max_width(self, max_width: u32) -> Self {
+        let mut result = self;
+        result.width.max_value = Saturating(max_width);
+        result
+    }
+    /// Replace the minimum height
+    pub fn with_min_height(self, min_height: u32) -> Self {
+        let mut result = self;
+        result.height.min_value = Saturating(min_height);
+        result
+    }
+    /// Replace the preferred height
+    pub fn with_preferred_height(self, preferred_height: u32) -> Self {
+        let mut result = self;
+        result.height.preferred_value = Saturating(preferred_height);
+        result
+    }
+    /// Replace the maximum height
+    pub fn with_max_height(self, max_height: u32) -> Self {
+        let mut result = self;
+        result.height.max_value = Saturating(max_height);
+        result
+    }
+    /// Remove the upper limit of the width
+    pub fn unbounded_width(self) -> Self {
+        Self {
+            width: self.width.expand_max(),
+            height: self.height,
+        }
+    }
+    /// Remove the upper limit of the height
+    pub fn unbounded_height(self) -> Self {
+        Self {
+            width: self.width,
+            height: self.height.expand_max(),
+        }
+    }
+    /// Remove the upper limit of width and height
+    pub fn unbounded(self) -> Self {
+        self.unbounded_width().unbounded_height()
+    }
+    ///
+    /// Smallest size containing this and another size (each value is the maximum of both)
+    ///
+    pub fn union(self, other: Self) -> Self {
+        let mut result = self;
+        result.width.expand(&other.width);
+        result.height.expand(&other.height);
+        result
+    }
+}
+
+///
+/// Grow all values of a size by a fixed amount (saturating)
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::geometry::Size;
+/// use simple_layout::ComponentSize;
+/// let bordered = ComponentSize::fixed_size(10, 8).unbounded_width() + Size::new(4, 4);
+/// assert_eq!(bordered.width().min_value(), 14);
+/// assert_eq!(bordered.width().max_value(), u32::MAX);
+/// assert_eq!(bordered.height().preferred_value(), 12);
+/// ```
+impl Add<Size> for ComponentSize {
+    type Output = ComponentSize;
+
+    fn add(self, rhs: Size) -> Self::Output {
+        ComponentSize {
+            width: self.width + Saturating(rhs.width),
+            height: self.height + Saturating(rhs.height),
+        }
+    }
+}
+
+///
+/// Shrink all values of a size by a fixed amount (saturating)
+///
+impl Sub<Size> for ComponentSize {
+    type Output = ComponentSize;
+
+    fn sub(self, rhs: Size) -> Self::Output {
+        ComponentSize {
+            width: self.width - Saturating(rhs.width),
+            height: self.height - Saturating(rhs.height),
+        }
+    }
+}
+
+///
+/// Multiply all values of a size by a factor (saturating)
+///
+/// # Examples
+///
+/// ```
+/// use simple_layout::ComponentSize;
+/// let zoomed = ComponentSize::fixed_size(6, 12).unbounded_width() * 2;
+/// assert_eq!(zoomed.width().min_value(), 12);
+/// assert_eq!(zoomed.width().max_value(), u32::MAX);
+/// assert_eq!(zoomed.height().preferred_value(), 24);
+/// ```
+impl Mul<u32> for ComponentSize {
+    type Output = ComponentSize;
+
+    fn mul(self, rhs: u32) -> Self::Output {
+        ComponentSize {
+            width: self.width * Saturating(rhs),
+            height: self.height * Saturating(rhs),
+        }
+    }
 }
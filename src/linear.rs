@@ -7,7 +7,10 @@ use embedded_graphics::{
     primitives::Rectangle,
 };
 
-use crate::{layoutable::Layoutable, ComponentSize, ValueRange};
+use crate::{
+    dirty::DirtyRegion, event::InputEvent, inspect::LayoutNode, layoutable::Layoutable,
+    ComponentSize, ValueRange,
+};
 
 pub trait Orientation {
     fn split_component_size(
@@ -123,6 +126,9 @@ pub trait LinearLayout<C: PixelColor, O: Orientation>: Sized {
         target: &mut impl DrawTarget<Color = C, Error = DrawError>,
         places: &[Rectangle],
     ) -> Result<(), DrawError>;
+    fn inspect_components(&self, places: &[Rectangle], children: &mut Vec<LayoutNode>);
+    fn collect_dirty_components(&self, places: &[Rectangle], dirty: &mut DirtyRegion);
+    fn handle_event_components(&self, places: &[Rectangle], event: InputEvent) -> bool;
 }
 
 #[derive(Default, Debug)]
@@ -175,8 +181,48 @@ impl<L: Layoutable<C>, C: PixelColor, O: Orientation> LinearLayout<C, O>
     ) -> Result<(), DrawError> {
         self.layout.draw_placed(target, places[0])
     }
+
+    #[inline]
+    fn inspect_components(&self, places: &[Rectangle], children: &mut Vec<LayoutNode>) {
+        children.push(LayoutNode::new(&self.layout, places[0]).with_weight(self.weight));
+    }
+
+    #[inline]
+    fn collect_dirty_components(&self, places: &[Rectangle], dirty: &mut DirtyRegion) {
+        self.layout.collect_dirty(places[0], dirty)
+    }
+
+    #[inline]
+    fn handle_event_components(&self, places: &[Rectangle], event: InputEvent) -> bool {
+        self.layout.handle_event(places[0], event)
+    }
 }
 
+///
+/// Linear stack of elements
+///
+/// The layout keeps no state, the sizes of the children are queried once per call and the placement is calculated
+/// again every time, so children may change their size between frames. Wrap expensive subtrees into `cached` if the
+/// same layout is drawn repeatedly.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::{FONT_6X12, FONT_8X13};
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{horizontal_layout, inspect, set_default_theme, themed_text, Role, Theme};
+/// let row = horizontal_layout::<_, BinaryColor>(themed_text(Role::Foreground, "21.3"), 0)
+///     .append(themed_text(Role::Foreground, "°C"), 0);
+/// let area = Rectangle::new(Point::zero(), Size::new(64, 16));
+/// set_default_theme(Theme::new(BinaryColor::On, BinaryColor::Off, BinaryColor::On, BinaryColor::On, &FONT_6X12));
+/// let narrow = inspect(&row, area).children()[1].position();
+/// // a wider font moves the unit, although the layout is placed onto the same area again
+/// set_default_theme(Theme::new(BinaryColor::On, BinaryColor::Off, BinaryColor::On, BinaryColor::On, &FONT_8X13));
+/// let wide = inspect(&row, area).children()[1].position();
+/// assert!(wide.top_left.x > narrow.top_left.x);
+/// ```
 pub struct LayoutableLinearLayout<C: PixelColor, O: Orientation, LL: LinearLayout<C, O>>(
     LL,
     PhantomData<C>,
@@ -228,8 +274,7 @@ impl<C: PixelColor, O: Orientation, LL: LinearLayout<C, O>> Layoutable<C>
     for LayoutableLinearLayout<C, O, LL>
 {
     fn size(&self) -> ComponentSize {
-        let mut sizes = vec![ComponentSize::default(); LL::len()].into_boxed_slice();
-        self.0.fill_sizes(&mut sizes);
+        let sizes = self.child_sizes();
         let mut total_along = ValueRange::default();
         let mut total_cross = ValueRange::default();
         for size in sizes.iter() {
@@ -244,11 +289,38 @@ impl<C: PixelColor, O: Orientation, LL: LinearLayout<C, O>> Layoutable<C>
         target: &mut impl DrawTarget<Color = C, Error = DrawError>,
         position: Rectangle,
     ) -> Result<(), DrawError> {
-        let (along_target, cross_target) = O::split_size(position.size);
-        let (mut along_offset, cross_offset) = O::split_point(position.top_left);
+        self.0
+            .draw_placed_components(target, &self.places(position))
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        self.0.inspect_components(&self.places(position), children)
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        self.0
+            .collect_dirty_components(&self.places(position), dirty)
+    }
 
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.0
+            .handle_event_components(&self.places(position), event)
+    }
+}
+
+impl<C: PixelColor, O: Orientation, LL: LinearLayout<C, O>> LayoutableLinearLayout<C, O, LL> {
+    fn child_sizes(&self) -> Box<[ComponentSize]> {
         let mut sizes = vec![ComponentSize::default(); LL::len()].into_boxed_slice();
         self.0.fill_sizes(&mut sizes);
+        sizes
+    }
+    fn places(&self, position: Rectangle) -> Box<[Rectangle]> {
+        self.calculate_places(position, &self.child_sizes())
+    }
+    fn calculate_places(&self, position: Rectangle, sizes: &[ComponentSize]) -> Box<[Rectangle]> {
+        let (along_target, cross_target) = O::split_size(position.size);
+        let (mut along_offset, cross_offset) = O::split_point(position.top_left);
+
         let sizes = sizes
             .iter()
             .map(|s| O::split_component_size(*s).0)
@@ -256,7 +328,7 @@ impl<C: PixelColor, O: Orientation, LL: LinearLayout<C, O>> Layoutable<C>
         let preferred_sizes = sizes.iter().map(|s| s.preferred_value).collect::<Box<_>>();
         let total_preferred: Saturating<u32> =
             preferred_sizes.iter().fold(Saturating(0), |s, v| s + v);
-        let places = match along_target.cmp(&total_preferred) {
+        match along_target.cmp(&total_preferred) {
             Ordering::Less => {
                 let min_sizes = sizes.iter().map(|s| s.min_value).collect::<Box<_>>();
                 let total_min = min_sizes.iter().fold(Saturating(0), |s, v| s + v);
@@ -357,8 +429,7 @@ impl<C: PixelColor, O: Orientation, LL: LinearLayout<C, O>> Layoutable<C>
             along_offset += Saturating(l.0 as i32);
             place
         })
-        .collect::<Box<_>>();
-        self.0.draw_placed_components(target, &places)
+        .collect::<Box<_>>()
     }
 }
 
@@ -433,6 +504,30 @@ impl<LL: LinearLayout<C, O>, L: Layoutable<C>, C: PixelColor, O: Orientation> Li
             .draw_placed_components(target, &places[0..idx])?;
         self.layoutable.draw_placed(target, places[idx])
     }
+
+    #[inline]
+    fn inspect_components(&self, places: &[Rectangle], children: &mut Vec<LayoutNode>) {
+        let idx = Self::len() - 1;
+        self.base_layout
+            .inspect_components(&places[0..idx], children);
+        children.push(LayoutNode::new(&self.layoutable, places[idx]).with_weight(self.weight));
+    }
+
+    #[inline]
+    fn collect_dirty_components(&self, places: &[Rectangle], dirty: &mut DirtyRegion) {
+        let idx = Self::len() - 1;
+        self.base_layout
+            .collect_dirty_components(&places[0..idx], dirty);
+        self.layoutable.collect_dirty(places[idx], dirty)
+    }
+
+    #[inline]
+    fn handle_event_components(&self, places: &[Rectangle], event: InputEvent) -> bool {
+        let idx = Self::len() - 1;
+        self.base_layout
+            .handle_event_components(&places[0..idx], event)
+            || self.layoutable.handle_event(places[idx], event)
+    }
 }
 
 ///
@@ -524,3 +619,80 @@ pub fn horizontal_layout<L: Layoutable<C>, C: PixelColor>(
         PhantomData,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use embedded_graphics::{
+        pixelcolor::BinaryColor,
+        prelude::{DrawTarget, Point, Size},
+        primitives::Rectangle,
+    };
+
+    use super::{horizontal_layout, vertical_layout};
+    use crate::{layoutable::Layoutable, ComponentSize};
+
+    /// element whose size can be changed between frames
+    struct Block(Cell<ComponentSize>);
+
+    impl Block {
+        fn new(preferred: u32, min: u32, max: u32) -> Self {
+            Self(Cell::new(ComponentSize::new(preferred, 1, min..max, 1..1)))
+        }
+    }
+
+    impl Layoutable<BinaryColor> for Block {
+        fn size(&self) -> ComponentSize {
+            self.0.get()
+        }
+
+        fn draw_placed<DrawError>(
+            &self,
+            _target: &mut impl DrawTarget<Color = BinaryColor, Error = DrawError>,
+            _position: Rectangle,
+        ) -> Result<(), DrawError> {
+            Ok(())
+        }
+    }
+
+    fn widths(places: &[Rectangle]) -> Vec<u32> {
+        places.iter().map(|place| place.size.width).collect()
+    }
+
+    #[test]
+    fn grows_children_by_weight() {
+        let layout = horizontal_layout(Block::new(10, 0, 100), 1).append(Block::new(10, 0, 100), 3);
+        let places = layout.places(Rectangle::new(Point::zero(), Size::new(60, 1)));
+        assert_eq!(widths(&places), vec![20, 40]);
+        assert_eq!(places[1].top_left, Point::new(20, 0));
+    }
+
+    #[test]
+    fn shrinks_children_down_to_their_minimum() {
+        let layout =
+            horizontal_layout(Block::new(20, 5, 100), 1).append(Block::new(20, 15, 100), 1);
+        let places = layout.places(Rectangle::new(Point::zero(), Size::new(36, 1)));
+        assert_eq!(widths(&places), vec![18, 18]);
+        // not even the minimum fits, the children keep it and overflow
+        let places = layout.places(Rectangle::new(Point::zero(), Size::new(10, 1)));
+        assert_eq!(widths(&places), vec![5, 15]);
+    }
+
+    #[test]
+    fn follows_changed_size_of_child() {
+        let first = Block::new(10, 10, 10);
+        let column = vertical_layout(
+            horizontal_layout(&first, 0).append(Block::new(10, 10, 10), 0),
+            0,
+        );
+        let row = horizontal_layout(&first, 0).append(Block::new(10, 10, 10), 0);
+        let area = Rectangle::new(Point::zero(), Size::new(25, 1));
+        assert_eq!(column.size().width.preferred_value.0, 20);
+        assert_eq!(row.places(area)[1].top_left, Point::new(10, 0));
+        // the same layouts are asked again for the next frame
+        first.0.set(ComponentSize::fixed_size(15, 1));
+        assert_eq!(column.size().width.preferred_value.0, 25);
+        assert_eq!(row.places(area)[1].top_left, Point::new(15, 0));
+    }
+}
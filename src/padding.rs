@@ -6,6 +6,9 @@ use embedded_graphics::geometry::Point;
 use embedded_graphics::prelude::{PixelColor, Size};
 use embedded_graphics::primitives::Rectangle;
 
+use crate::dirty::DirtyRegion;
+use crate::event::InputEvent;
+use crate::inspect::LayoutNode;
 use crate::prelude::Layoutable;
 use crate::ComponentSize;
 
@@ -73,20 +76,51 @@ impl<C: PixelColor, L: Layoutable<C>> Layoutable<C> for Padding<C, L> {
         target: &mut impl DrawTarget<Color = C, Error = DrawError>,
         position: Rectangle,
     ) -> Result<(), DrawError> {
+        self.layoutable
+            .draw_placed(target, self.inner_position(position))
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(
+            LayoutNode::new(&self.layoutable, self.inner_position(position)).with_insets(
+                Size::new(
+                    (self.left + self.right).max(0) as u32,
+                    (self.top + self.bottom).max(0) as u32,
+                ),
+            ),
+        );
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        self.layoutable
+            .collect_dirty(self.inner_position(position), dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.layoutable
+            .handle_event(self.inner_position(position), event)
+    }
+}
+
+impl<C: PixelColor, L: Layoutable<C>> Padding<C, L> {
+    fn inner_position(&self, position: Rectangle) -> Rectangle {
         let Rectangle {
             top_left: Point { x, y },
             size: Size { width, height },
         } = position;
-        let position = Rectangle {
+        Rectangle {
             top_left: Point {
                 x: x + self.left,
                 y: y + self.top,
             },
             size: Size {
-                width: (Saturating(width as i32) - Saturating(self.left + self.right)).0 as u32,
-                height: (Saturating(height as i32) - Saturating(self.top + self.bottom)).0 as u32,
+                width: (Saturating(width as i32) - Saturating(self.left + self.right))
+                    .0
+                    .max(0) as u32,
+                height: (Saturating(height as i32) - Saturating(self.top + self.bottom))
+                    .0
+                    .max(0) as u32,
             },
-        };
-        self.layoutable.draw_placed(target, position)
+        }
     }
 }
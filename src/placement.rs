@@ -8,6 +8,9 @@ use embedded_graphics::primitives::Rectangle;
 #[cfg(feature = "log")]
 use log::warn;
 
+use crate::dirty::DirtyRegion;
+use crate::event::InputEvent;
+use crate::inspect::LayoutNode;
 use crate::layoutable::Layoutable;
 use crate::ComponentSize;
 
@@ -75,4 +78,16 @@ impl<L: Layoutable<C>, C: PixelColor, F: FnMut(Rectangle)> Layoutable<C>
         }
         self.layoutable.draw_placed(target, position)
     }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        children.push(LayoutNode::new(&self.layoutable, position));
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        self.layoutable.collect_dirty(position, dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.layoutable.handle_event(position, event)
+    }
 }
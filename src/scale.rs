@@ -1,8 +1,14 @@
 use embedded_graphics::{
-    draw_target::DrawTarget, geometry::Point, pixelcolor::PixelColor, primitives::Rectangle, Pixel,
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{Point, Size},
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+    Pixel,
 };
 
+use crate::draw::TransformDrawable;
 use crate::layoutable::Layoutable;
+use crate::transform::Transform;
 use crate::{ComponentSize, ValueRange};
 
 ///
@@ -10,11 +16,13 @@ use crate::{ComponentSize, ValueRange};
 ///
 /// # Arguments
 ///
-/// * `value`: value to be represented on the scale (0.0-1.0)
+/// * `value`: value to be represented on the scale (0.0-1.0), values outside are clamped
 /// * `color`: color of the dots
 ///
 /// returns: impl Layoutable<C>+Sized
 ///
+/// See `progress_bar` for a configurable variant.
+///
 pub fn scale<C: PixelColor>(value: f32, color: C) -> impl Layoutable<C> {
     Scale { value, color }
 }
@@ -37,20 +45,303 @@ impl<C: PixelColor> Layoutable<C> for Scale<C> {
         target: &mut impl DrawTarget<Color = C, Error = DrawError>,
         position: Rectangle,
     ) -> Result<(), DrawError> {
-        let width = position.size.width;
-        let x = position.top_left.x;
-        let y = position.top_left.y;
-        let total_dot_count = (width - 5) / 3;
-        let x_offset = (width - total_dot_count * 3 - 4) / 2;
-        let enabled_dot_count =
-            ((self.value * total_dot_count as f32).round() as u32).min(total_dot_count);
-
-        let pixels = [1, total_dot_count * 3 + 3]
-            .into_iter()
-            .chain((0..enabled_dot_count).flat_map(|d| [d * 3 + 3, d * 3 + 4]))
-            .map(|p| (p + x_offset) as i32)
-            .flat_map(|p| [Point { x: x + p, y: y + 1 }, Point { x: x + p, y: y + 2 }])
-            .map(|p| Pixel(p, self.color));
-        target.draw_iter(pixels)
+        draw_dots(target, position, self.value, self.color)
+    }
+}
+
+///
+/// Draw the dots of a scale, shared by all elements looking like a scale
+///
+/// Nothing is drawn onto areas too small for the end markers.
+///
+pub(crate) fn draw_dots<C: PixelColor, DrawError>(
+    target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+    position: Rectangle,
+    value: f32,
+    color: C,
+) -> Result<(), DrawError> {
+    let Size { width, height } = position.size;
+    if width < 5 || height < 3 {
+        return Ok(());
+    }
+    let x = position.top_left.x;
+    let y = position.top_left.y;
+    let total_dot_count = (width - 5) / 3;
+    let x_offset = (width - total_dot_count * 3 - 4) / 2;
+    let enabled_dot_count = (value.clamp(0.0, 1.0) * total_dot_count as f32).round() as u32;
+
+    let pixels = [1, total_dot_count * 3 + 3]
+        .into_iter()
+        .chain((0..enabled_dot_count).flat_map(|d| [d * 3 + 3, d * 3 + 4]))
+        .map(|p| (p + x_offset) as i32)
+        .flat_map(|p| [Point { x: x + p, y: y + 1 }, Point { x: x + p, y: y + 2 }])
+        .map(|p| Pixel(p, color));
+    target.draw_iter(pixels)
+}
+
+///
+/// How the filled part of a progress bar is drawn
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum BarFill {
+    /// one continuous bar
+    #[default]
+    Solid,
+    /// segments across the whole thickness of the bar
+    Segmented,
+    /// square dots centered on the bar
+    Dotted,
+}
+
+///
+/// Configurable bar showing a value inside a range, e.g. a progress, a battery or a fill level
+///
+/// The bar takes all the space along its orientation, so it should be placed with a weight or into `expand`.
+/// Values outside the range are clamped and areas too small for the bar draw only what fits.
+///
+/// # Arguments
+///
+/// * `value`: value to be represented, between 0.0 and 1.0 unless another range is configured
+/// * `color`: color of the bar and the ticks
+///
+/// returns: ProgressBar<C>
+///
+/// # Examples
+///
+/// battery level with 5 segments growing from the bottom
+/// ```
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{progress_bar, BarFill, Layoutable};
+/// let battery = progress_bar(3.9, BinaryColor::On)
+///     .with_range(3.3, 4.2)
+///     .vertical()
+///     .with_fill(BarFill::Segmented)
+///     .with_segment(3, 1)
+///     .with_thickness(3);
+/// assert_eq!(battery.size().preferred_size().width, 3);
+/// let mut display = MockDisplay::<BinaryColor>::new();
+/// battery
+///     .draw_placed(&mut display, Rectangle::new(Point::zero(), Size::new(3, 19)))
+///     .unwrap();
+/// display.assert_pattern(&[
+///     "   ", "   ", "   ", "   ",
+///     "   ", "   ", "   ", "   ",
+///     "###", "###", "###", "   ",
+///     "###", "###", "###", "   ",
+///     "###", "###", "###",
+/// ]);
+/// ```
+/// horizontal percentage with ticks every 25%
+/// ```
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use simple_layout::prelude::{progress_bar, BarFill, Layoutable};
+/// let progress = progress_bar(42.0, BinaryColor::On)
+///     .with_range(0.0, 100.0)
+///     .with_fill(BarFill::Dotted)
+///     .with_ticks(5);
+/// // the ticks are drawn below the bar
+/// assert_eq!(progress.size().preferred_size().height, 7);
+/// ```
+pub fn progress_bar<C: PixelColor>(value: f32, color: C) -> ProgressBar<C> {
+    ProgressBar {
+        value,
+        min: 0.0,
+        max: 1.0,
+        color,
+        vertical: false,
+        fill: BarFill::default(),
+        segment_size: 2,
+        segment_gap: 1,
+        thickness: 4,
+        ticks: 0,
+    }
+}
+
+/// space between the bar and the ticks
+const TICK_GAP: u32 = 1;
+/// length of the tick marks
+const TICK_LENGTH: u32 = 2;
+
+///
+/// Bar showing a value inside a range, see `progress_bar`
+///
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ProgressBar<C: PixelColor> {
+    value: f32,
+    min: f32,
+    max: f32,
+    color: C,
+    vertical: bool,
+    fill: BarFill,
+    segment_size: u32,
+    segment_gap: u32,
+    thickness: u32,
+    ticks: u32,
+}
+
+impl<C: PixelColor> ProgressBar<C> {
+    ///
+    /// Change the range of the value, default is 0.0 - 1.0
+    ///
+    pub fn with_range(self, min: f32, max: f32) -> Self {
+        Self { min, max, ..self }
+    }
+    ///
+    /// Let the bar grow from the bottom to the top
+    ///
+    pub fn vertical(self) -> Self {
+        Self {
+            vertical: true,
+            ..self
+        }
+    }
+    ///
+    /// Change how the filled part is drawn, default is `BarFill::Solid`
+    ///
+    pub fn with_fill(self, fill: BarFill) -> Self {
+        Self { fill, ..self }
+    }
+    ///
+    /// Change the length of the segments or dots and the space between them, default is 2 and 1 pixel
+    ///
+    pub fn with_segment(self, segment_size: u32, segment_gap: u32) -> Self {
+        Self {
+            segment_size: segment_size.max(1),
+            segment_gap,
+            ..self
+        }
+    }
+    ///
+    /// Change the thickness of the bar, default is 4 pixel
+    ///
+    pub fn with_thickness(self, thickness: u32) -> Self {
+        Self { thickness, ..self }
+    }
+    ///
+    /// Draw evenly distributed tick marks from the start to the end of the bar beside it
+    ///
+    /// # Arguments
+    ///
+    /// * `ticks`: count of tick marks, 0 draws none
+    ///
+    /// returns: ProgressBar<C>
+    ///
+    pub fn with_ticks(self, ticks: u32) -> Self {
+        Self { ticks, ..self }
+    }
+    fn fraction(&self) -> f32 {
+        if self.max > self.min {
+            ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+    fn draw_horizontal<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let Rectangle {
+            top_left,
+            size: Size { width, .. },
+        } = position;
+        let target = &mut target.clipped(&position);
+        let fraction = self.fraction();
+        let thickness = self.thickness;
+        match self.fill {
+            BarFill::Solid => {
+                let filled = (fraction * width as f32).round() as u32;
+                target.fill_solid(
+                    &Rectangle::new(top_left, Size::new(filled, thickness)),
+                    self.color,
+                )?;
+            }
+            BarFill::Segmented | BarFill::Dotted => {
+                let pitch = self.segment_size + self.segment_gap;
+                let count = (width + self.segment_gap) / pitch;
+                let offset = (width + self.segment_gap - count * pitch) / 2;
+                let enabled = (fraction * count as f32).round() as u32;
+                let (segment_offset, segment_thickness) = if self.fill == BarFill::Dotted {
+                    let size = self.segment_size.min(thickness);
+                    ((thickness - size) / 2, size)
+                } else {
+                    (0, thickness)
+                };
+                for segment in 0..enabled {
+                    target.fill_solid(
+                        &Rectangle::new(
+                            top_left
+                                + Point::new(
+                                    (offset + segment * pitch) as i32,
+                                    segment_offset as i32,
+                                ),
+                            Size::new(self.segment_size, segment_thickness),
+                        ),
+                        self.color,
+                    )?;
+                }
+            }
+        }
+        if self.ticks > 0 {
+            let last = width.saturating_sub(1);
+            let tick_y = (thickness + TICK_GAP) as i32;
+            for tick in 0..self.ticks {
+                let x = if self.ticks > 1 {
+                    (tick as f32 * last as f32 / (self.ticks - 1) as f32).round() as u32
+                } else {
+                    last / 2
+                };
+                target.fill_solid(
+                    &Rectangle::new(
+                        top_left + Point::new(x as i32, tick_y),
+                        Size::new(1, TICK_LENGTH),
+                    ),
+                    self.color,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<C: PixelColor> Layoutable<C> for ProgressBar<C> {
+    fn size(&self) -> ComponentSize {
+        let along = ValueRange::fixed(11).expand_max();
+        let mut across = self.thickness;
+        if self.ticks > 0 {
+            across += TICK_GAP + TICK_LENGTH;
+        }
+        let across = ValueRange::fixed(across);
+        if self.vertical {
+            ComponentSize {
+                width: across,
+                height: along,
+            }
+        } else {
+            ComponentSize {
+                width: along,
+                height: across,
+            }
+        }
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        if self.vertical {
+            // a vertical bar is a horizontal one turned so it grows from the bottom
+            let transform = Transform::Rotate270;
+            self.draw_horizontal(
+                &mut TransformDrawable::new(target, transform, position),
+                Rectangle::new(Point::zero(), transform.transform_size(position.size)),
+            )
+        } else {
+            self.draw_horizontal(target, position)
+        }
     }
 }
@@ -0,0 +1,807 @@
+use std::cell::Cell;
+use std::ops::DerefMut;
+use std::sync::Mutex;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    pixelcolor::PixelColor,
+    prelude::Primitive,
+    primitives::{PrimitiveStyle, Rectangle},
+    text::renderer::TextRenderer,
+    Drawable,
+};
+#[cfg(feature = "log")]
+use log::warn;
+
+use crate::align::center;
+use crate::button::{text_button, ButtonState, ButtonStyle};
+use crate::dirty::DirtyRegion;
+use crate::draw::TransformDrawable;
+use crate::event::{InputEvent, Key};
+use crate::focus::FocusManager;
+use crate::inspect::LayoutNode;
+use crate::layoutable::Layoutable;
+use crate::scale::draw_dots;
+use crate::transform::Transform;
+use crate::{ComponentSize, ValueRange};
+
+///
+/// Appearance of the track of a slider or stepper
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum TrackStyle {
+    /// 2x2 dots like a `scale`
+    #[default]
+    Dots,
+    /// frame filled up to the current value
+    Solid,
+}
+
+///
+/// Range of values which can be selected and the size of a single step
+///
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Steps {
+    min: f32,
+    max: f32,
+    step: Option<f32>,
+}
+
+impl Steps {
+    fn step(&self) -> f32 {
+        self.step.unwrap_or((self.max - self.min) / 10.0)
+    }
+    fn fraction(&self, value: f32) -> f32 {
+        if self.max > self.min {
+            ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+    fn value_at(&self, fraction: f32) -> f32 {
+        self.snap(self.min + fraction * (self.max - self.min))
+    }
+    fn snap(&self, value: f32) -> f32 {
+        let step = self.step();
+        let value = if step > 0.0 {
+            self.min + ((value - self.min) / step).round() * step
+        } else {
+            value
+        };
+        value.min(self.max).max(self.min)
+    }
+    /// change of the value by a single key press or encoder step
+    fn increment(&self) -> f32 {
+        let step = self.step();
+        if step > 0.0 {
+            step
+        } else {
+            // continuous values are still changed in tenths of the range by keys and encoder
+            (self.max - self.min) / 10.0
+        }
+    }
+    fn offset(&self, value: f32, steps: i32) -> f32 {
+        self.snap(value + steps as f32 * self.increment())
+    }
+}
+
+impl Default for Steps {
+    fn default() -> Self {
+        Self {
+            min: 0.0,
+            max: 1.0,
+            step: None,
+        }
+    }
+}
+
+///
+/// Track showing a value between 0.0 and 1.0
+///
+struct Track<C: PixelColor> {
+    fraction: f32,
+    color: C,
+    style: TrackStyle,
+    vertical: bool,
+}
+
+impl<C: PixelColor> Track<C> {
+    fn draw_horizontal<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        match self.style {
+            TrackStyle::Dots => draw_dots(target, position, self.fraction, self.color),
+            TrackStyle::Solid => {
+                position
+                    .into_styled(PrimitiveStyle::with_stroke(self.color, 1))
+                    .draw(target)?;
+                let Size { width, height } = position.size;
+                let filled = (self.fraction * width.saturating_sub(2) as f32).round() as u32;
+                target.fill_solid(
+                    &Rectangle::new(
+                        position.top_left + Point::new(1, 1),
+                        Size::new(filled, height.saturating_sub(2)),
+                    ),
+                    self.color,
+                )
+            }
+        }
+    }
+}
+
+impl<C: PixelColor> Layoutable<C> for Track<C> {
+    fn size(&self) -> ComponentSize {
+        let along = ValueRange::fixed(11).expand_max();
+        let across = ValueRange::fixed(4);
+        if self.vertical {
+            ComponentSize {
+                width: across,
+                height: along,
+            }
+        } else {
+            ComponentSize {
+                width: along,
+                height: across,
+            }
+        }
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        if self.vertical {
+            // a vertical track is a horizontal one turned so the minimum is at the bottom
+            let transform = Transform::Rotate270;
+            self.draw_horizontal(
+                &mut TransformDrawable::new(target, transform, position),
+                Rectangle::new(Point::zero(), transform.transform_size(position.size)),
+            )
+        } else {
+            self.draw_horizontal(target, position)
+        }
+    }
+}
+
+///
+/// State of a slider kept between frames
+///
+#[derive(Debug, Default)]
+pub struct SliderState {
+    dragging: Cell<bool>,
+    drawn: Cell<Option<(f32, Rectangle)>>,
+}
+
+impl SliderState {
+    /// true while the pointer is held down on the slider
+    pub fn is_dragging(&self) -> bool {
+        self.dragging.get()
+    }
+}
+
+///
+/// Horizontal slider selecting a value by pointer or encoder
+///
+/// Pressing the pointer onto the slider and moving it selects the value at the pointer position. While the slider is
+/// focused (see `with_focus`), encoder turns, `Key::Right` and `Key::Up` increase the value by one step, `Key::Left`
+/// and `Key::Down` decrease it.
+///
+/// The slider is dirty when its value changed since it was drawn the last time.
+///
+/// # Arguments
+///
+/// * `state`: state of the slider kept between frames
+/// * `value`: current value
+/// * `color`: color of the track
+/// * `on_change`: callback receiving the newly selected value
+///
+/// returns: Slider<C, F, ()>
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{dispatch, slider, FocusManager, InputEvent, SliderState};
+/// let volume = Cell::new(50.0);
+/// let state = SliderState::default();
+/// let manager = FocusManager::default();
+/// let volume_slider = slider(&state, volume.get(), BinaryColor::On, |v| volume.set(v))
+///     .with_range(0.0, 100.0)
+///     .with_step(5.0)
+///     .with_focus(&manager, "volume");
+/// let area = Rectangle::new(Point::zero(), Size::new(41, 4));
+/// dispatch(&volume_slider, area, InputEvent::Press(Point::new(10, 1)));
+/// assert_eq!(volume.get(), 25.0);
+/// dispatch(&volume_slider, area, InputEvent::Move(Point::new(40, 1)));
+/// dispatch(&volume_slider, area, InputEvent::Release(Point::new(40, 1)));
+/// assert_eq!(volume.get(), 100.0);
+/// // pressing the slider focused it, so the encoder changes the value now
+/// assert_eq!(manager.focused(), Some("volume"));
+/// assert!(dispatch(&volume_slider, area, InputEvent::Encoder(-2)));
+/// assert_eq!(volume.get(), 90.0);
+/// // without focus the encoder is left to other elements
+/// manager.focus(None);
+/// assert!(!dispatch(&volume_slider, area, InputEvent::Encoder(-2)));
+/// ```
+pub fn slider<'a, C: PixelColor, F: FnMut(f32)>(
+    state: &'a SliderState,
+    value: f32,
+    color: C,
+    on_change: F,
+) -> Slider<'a, C, F, ()> {
+    Slider {
+        state,
+        value: Cell::new(value),
+        steps: Steps::default(),
+        color,
+        track: TrackStyle::default(),
+        vertical: false,
+        on_change: Mutex::new(on_change),
+        focus: None,
+    }
+}
+
+///
+/// Vertical slider with the minimum at the bottom, see `slider`
+///
+/// # Arguments
+///
+/// * `state`: state of the slider kept between frames
+/// * `value`: current value
+/// * `color`: color of the track
+/// * `on_change`: callback receiving the newly selected value
+///
+/// returns: Slider<C, F, ()>
+///
+pub fn vertical_slider<'a, C: PixelColor, F: FnMut(f32)>(
+    state: &'a SliderState,
+    value: f32,
+    color: C,
+    on_change: F,
+) -> Slider<'a, C, F, ()> {
+    Slider {
+        vertical: true,
+        ..slider(state, value, color, on_change)
+    }
+}
+
+///
+/// Element selecting a value along a track, see `slider` and `vertical_slider`
+///
+pub struct Slider<'a, C: PixelColor, F: FnMut(f32), K: Copy + Eq> {
+    state: &'a SliderState,
+    value: Cell<f32>,
+    steps: Steps,
+    color: C,
+    track: TrackStyle,
+    vertical: bool,
+    on_change: Mutex<F>,
+    focus: Option<(&'a FocusManager<K>, K)>,
+}
+
+impl<'a, C: PixelColor, F: FnMut(f32), K: Copy + Eq> Slider<'a, C, F, K> {
+    ///
+    /// Change the range of selectable values, default is 0.0 - 1.0
+    ///
+    pub fn with_range(self, min: f32, max: f32) -> Self {
+        Self {
+            steps: Steps {
+                min,
+                max,
+                ..self.steps
+            },
+            ..self
+        }
+    }
+    ///
+    /// Change the size of a step, 0.0 selects any value. Default is a tenth of the range
+    ///
+    /// Without steps keys and encoder turns change the value by a tenth of the range.
+    ///
+    pub fn with_step(self, step: f32) -> Self {
+        Self {
+            steps: Steps {
+                step: Some(step),
+                ..self.steps
+            },
+            ..self
+        }
+    }
+    ///
+    /// Change the appearance of the track
+    ///
+    pub fn with_track(self, track: TrackStyle) -> Self {
+        Self { track, ..self }
+    }
+    ///
+    /// Change the value by keys and encoder turns while the slider is focused
+    ///
+    /// The slider is registered with the manager when it is drawn, so it can be reached by navigation like a
+    /// `focusable` element. Pressing the slider focuses it.
+    ///
+    /// # Arguments
+    ///
+    /// * `manager`: focus manager of the screen
+    /// * `key`: unique key of this slider
+    ///
+    /// returns: Slider<C, F, K>
+    ///
+    pub fn with_focus<K2: Copy + Eq>(
+        self,
+        manager: &'a FocusManager<K2>,
+        key: K2,
+    ) -> Slider<'a, C, F, K2> {
+        Slider {
+            state: self.state,
+            value: self.value,
+            steps: self.steps,
+            color: self.color,
+            track: self.track,
+            vertical: self.vertical,
+            on_change: self.on_change,
+            focus: Some((manager, key)),
+        }
+    }
+    fn is_focused(&self) -> bool {
+        self.focus
+            .map(|(manager, key)| manager.is_focused(key))
+            .unwrap_or(false)
+    }
+    fn track(&self) -> Track<C> {
+        Track {
+            fraction: self.steps.fraction(self.value.get()),
+            color: self.color,
+            style: self.track,
+            vertical: self.vertical,
+        }
+    }
+    fn fraction_at(&self, point: Point, position: Rectangle) -> f32 {
+        let Point { x, y } = point - position.top_left;
+        let (offset, length) = if self.vertical {
+            (position.size.height as i32 - 1 - y, position.size.height)
+        } else {
+            (x, position.size.width)
+        };
+        if length > 1 {
+            (offset as f32 / (length - 1) as f32).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+    fn change(&self, value: f32) {
+        if self.value.replace(value) == value {
+            return;
+        }
+        if let Ok(mut mutex) = self.on_change.try_lock() {
+            (mutex.deref_mut())(value);
+        } else {
+            #[cfg(feature = "log")]
+            warn!("Cannot lock change callback");
+        }
+    }
+}
+
+impl<'a, C: PixelColor, F: FnMut(f32), K: Copy + Eq> Layoutable<C> for Slider<'a, C, F, K> {
+    fn size(&self) -> ComponentSize {
+        self.track().size()
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        self.state.drawn.set(Some((self.value.get(), position)));
+        if let Some((manager, key)) = self.focus {
+            manager.register(key, position, self.is_focused());
+        }
+        self.track().draw_placed(target, position)
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        if self.state.drawn.get() != Some((self.value.get(), position)) {
+            dirty.add(position)
+        }
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        match event {
+            InputEvent::Press(point) if position.contains(point) => {
+                self.state.dragging.set(true);
+                if let Some((manager, key)) = self.focus {
+                    manager.focus(Some(key));
+                }
+                self.change(self.steps.value_at(self.fraction_at(point, position)));
+                true
+            }
+            InputEvent::Move(point) if self.state.is_dragging() => {
+                self.change(self.steps.value_at(self.fraction_at(point, position)));
+                true
+            }
+            InputEvent::Release(point) if self.state.dragging.replace(false) => {
+                self.change(self.steps.value_at(self.fraction_at(point, position)));
+                true
+            }
+            InputEvent::Encoder(steps) if self.is_focused() => {
+                self.change(self.steps.offset(self.value.get(), steps));
+                true
+            }
+            InputEvent::Key(Key::Right | Key::Up) if self.is_focused() => {
+                self.change(self.steps.offset(self.value.get(), 1));
+                true
+            }
+            InputEvent::Key(Key::Left | Key::Down) if self.is_focused() => {
+                self.change(self.steps.offset(self.value.get(), -1));
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+///
+/// State of a stepper kept between frames
+///
+#[derive(Debug, Default)]
+pub struct StepperState {
+    minus: ButtonState,
+    plus: ButtonState,
+    drawn: Cell<Option<(f32, Rectangle)>>,
+}
+
+///
+/// Track between a "-" and a "+" button changing a value by one step on each click
+///
+/// While the stepper is focused (see `with_focus`), encoder turns and the keys `Key::Right`, `Key::Up`, `Key::Left`
+/// and `Key::Down` change the value like on a `slider`.
+///
+/// # Arguments
+///
+/// * `state`: state of the stepper kept between frames
+/// * `style`: appearance of the buttons, the track is drawn in the content color of the normal look
+/// * `character_style`: font of the button labels
+/// * `value`: current value
+/// * `on_change`: callback receiving the newly selected value
+///
+/// returns: Stepper<C, S, F, ()>
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Size};
+/// use embedded_graphics::primitives::Rectangle;
+/// use simple_layout::prelude::{dispatch, inspect, stepper, ButtonStyle, InputEvent, StepperState};
+/// let brightness = Cell::new(3.0);
+/// let state = StepperState::default();
+/// let brightness_stepper = stepper(
+///     &state,
+///     ButtonStyle::new(BinaryColor::On, BinaryColor::Off),
+///     MonoTextStyle::new(&FONT_6X12, BinaryColor::On),
+///     brightness.get(),
+///     |v| brightness.set(v),
+/// )
+/// .with_range(0.0, 5.0)
+/// .with_step(1.0);
+/// let area = Rectangle::new(Point::zero(), Size::new(80, 24));
+/// // the track fills the space between the buttons
+/// let tree = inspect(&brightness_stepper, area);
+/// assert_eq!(tree.children()[1].position(), Rectangle::new(Point::new(24, 0), Size::new(32, 24)));
+/// // click onto the "+" button on the right side
+/// dispatch(&brightness_stepper, area, InputEvent::Press(Point::new(70, 10)));
+/// dispatch(&brightness_stepper, area, InputEvent::Release(Point::new(70, 10)));
+/// assert_eq!(brightness.get(), 4.0);
+/// ```
+pub fn stepper<'a, C: PixelColor, S: TextRenderer<Color = C> + Copy, F: FnMut(f32)>(
+    state: &'a StepperState,
+    style: ButtonStyle<C>,
+    character_style: S,
+    value: f32,
+    on_change: F,
+) -> Stepper<'a, C, S, F, ()> {
+    Stepper {
+        state,
+        style,
+        character_style,
+        value: Cell::new(value),
+        steps: Steps::default(),
+        track: TrackStyle::default(),
+        on_change: Mutex::new(on_change),
+        focus: None,
+    }
+}
+
+///
+/// Element changing a value with buttons, see `stepper`
+///
+pub struct Stepper<
+    'a,
+    C: PixelColor,
+    S: TextRenderer<Color = C> + Copy,
+    F: FnMut(f32),
+    K: Copy + Eq,
+> {
+    state: &'a StepperState,
+    style: ButtonStyle<C>,
+    character_style: S,
+    value: Cell<f32>,
+    steps: Steps,
+    track: TrackStyle,
+    on_change: Mutex<F>,
+    focus: Option<(&'a FocusManager<K>, K)>,
+}
+
+impl<'a, C: PixelColor, S: TextRenderer<Color = C> + Copy, F: FnMut(f32), K: Copy + Eq>
+    Stepper<'a, C, S, F, K>
+{
+    ///
+    /// Change the range of selectable values, default is 0.0 - 1.0
+    ///
+    pub fn with_range(self, min: f32, max: f32) -> Self {
+        Self {
+            steps: Steps {
+                min,
+                max,
+                ..self.steps
+            },
+            ..self
+        }
+    }
+    ///
+    /// Change the size of a step, default is a tenth of the range
+    ///
+    pub fn with_step(self, step: f32) -> Self {
+        Self {
+            steps: Steps {
+                step: Some(step),
+                ..self.steps
+            },
+            ..self
+        }
+    }
+    ///
+    /// Change the appearance of the track
+    ///
+    pub fn with_track(self, track: TrackStyle) -> Self {
+        Self { track, ..self }
+    }
+    ///
+    /// Change the value by keys and encoder turns while the stepper is focused
+    ///
+    /// The stepper is registered with the manager when it is drawn, so it can be reached by navigation like a
+    /// `focusable` element. Pressing one of its buttons focuses it.
+    ///
+    pub fn with_focus<K2: Copy + Eq>(
+        self,
+        manager: &'a FocusManager<K2>,
+        key: K2,
+    ) -> Stepper<'a, C, S, F, K2> {
+        Stepper {
+            state: self.state,
+            style: self.style,
+            character_style: self.character_style,
+            value: self.value,
+            steps: self.steps,
+            track: self.track,
+            on_change: self.on_change,
+            focus: Some((manager, key)),
+        }
+    }
+    fn is_focused(&self) -> bool {
+        self.focus
+            .map(|(manager, key)| manager.is_focused(key))
+            .unwrap_or(false)
+    }
+    fn step(&self, steps: i32) {
+        let value = self.steps.offset(self.value.get(), steps);
+        if self.value.replace(value) == value {
+            return;
+        }
+        if let Ok(mut mutex) = self.on_change.try_lock() {
+            (mutex.deref_mut())(value);
+        } else {
+            #[cfg(feature = "log")]
+            warn!("Cannot lock change callback");
+        }
+    }
+    fn minus(&self) -> impl Layoutable<C> + '_ {
+        text_button(
+            &self.state.minus,
+            self.style,
+            || self.step(-1),
+            "-",
+            self.character_style,
+        )
+    }
+    fn plus(&self) -> impl Layoutable<C> + '_ {
+        text_button(
+            &self.state.plus,
+            self.style,
+            || self.step(1),
+            "+",
+            self.character_style,
+        )
+    }
+    fn track(&self) -> impl Layoutable<C> {
+        center(Track {
+            fraction: self.steps.fraction(self.value.get()),
+            color: self.style.normal.content,
+            style: self.track,
+            vertical: false,
+        })
+    }
+    /// places of the "-" button, the track and the "+" button
+    fn places(&self, position: Rectangle) -> [Rectangle; 3] {
+        let Size { width, height } = position.size;
+        let minus_width = self.minus().size().preferred_size().width.min(width);
+        let plus_width = self
+            .plus()
+            .size()
+            .preferred_size()
+            .width
+            .min(width - minus_width);
+        let track_width = width - minus_width - plus_width;
+        let top_left = position.top_left;
+        [
+            Rectangle::new(top_left, Size::new(minus_width, height)),
+            Rectangle::new(
+                top_left + Point::new(minus_width as i32, 0),
+                Size::new(track_width, height),
+            ),
+            Rectangle::new(
+                top_left + Point::new((minus_width + track_width) as i32, 0),
+                Size::new(plus_width, height),
+            ),
+        ]
+    }
+}
+
+impl<'a, C: PixelColor, S: TextRenderer<Color = C> + Copy, F: FnMut(f32), K: Copy + Eq>
+    Layoutable<C> for Stepper<'a, C, S, F, K>
+{
+    fn size(&self) -> ComponentSize {
+        let mut width = ValueRange::default();
+        let mut height = ValueRange::default();
+        for size in [self.minus().size(), self.track().size(), self.plus().size()] {
+            width += size.width;
+            height.expand(&size.height);
+        }
+        ComponentSize { width, height }
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let [minus, track, plus] = self.places(position);
+        self.state.drawn.set(Some((self.value.get(), track)));
+        if let Some((manager, key)) = self.focus {
+            manager.register(key, position, self.is_focused());
+        }
+        self.minus().draw_placed(target, minus)?;
+        self.track().draw_placed(target, track)?;
+        self.plus().draw_placed(target, plus)
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        let [minus, track, plus] = self.places(position);
+        children.push(LayoutNode::new(&self.minus(), minus));
+        children.push(LayoutNode::new(&self.track(), track));
+        children.push(LayoutNode::new(&self.plus(), plus));
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        let [minus, track, plus] = self.places(position);
+        self.minus().collect_dirty(minus, dirty);
+        if self.state.drawn.get() != Some((self.value.get(), track)) {
+            dirty.add(track);
+        }
+        self.plus().collect_dirty(plus, dirty);
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        let [minus, _, plus] = self.places(position);
+        if let (InputEvent::Press(point), Some((manager, key))) = (event, self.focus) {
+            if position.contains(point) {
+                manager.focus(Some(key));
+            }
+        }
+        if self.minus().handle_event(minus, event) || self.plus().handle_event(plus, event) {
+            return true;
+        }
+        match event {
+            InputEvent::Encoder(steps) if self.is_focused() => {
+                self.step(steps);
+                true
+            }
+            InputEvent::Key(Key::Right | Key::Up) if self.is_focused() => {
+                self.step(1);
+                true
+            }
+            InputEvent::Key(Key::Left | Key::Down) if self.is_focused() => {
+                self.step(-1);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+    use embedded_graphics::mono_font::MonoTextStyle;
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use embedded_graphics::prelude::{Point, Size};
+    use embedded_graphics::primitives::Rectangle;
+
+    use super::{slider, stepper, SliderState, StepperState, Steps};
+    use crate::button::ButtonStyle;
+    use crate::event::{dispatch, InputEvent, Key};
+    use crate::focus::{FocusManager, Navigation};
+    use crate::layoutable::Layoutable;
+
+    #[test]
+    fn continuous_steps_change_by_a_tenth_of_the_range() {
+        let steps = Steps {
+            min: 0.0,
+            max: 50.0,
+            step: Some(0.0),
+        };
+        assert_eq!(steps.offset(12.3, 1), 17.3);
+        assert_eq!(steps.offset(2.0, -1), 0.0);
+        assert_eq!(steps.snap(12.3), 12.3);
+    }
+
+    #[test]
+    fn continuous_slider_follows_encoder() {
+        let value = std::cell::Cell::new(0.5);
+        let state = SliderState::default();
+        let manager = FocusManager::default();
+        let continuous = slider(&state, value.get(), BinaryColor::On, |v| value.set(v))
+            .with_step(0.0)
+            .with_focus(&manager, 0);
+        let area = Rectangle::new(Point::zero(), Size::new(20, 4));
+        manager.focus(Some(0));
+        assert!(dispatch(&continuous, area, InputEvent::Encoder(2)));
+        assert!((value.get() - 0.7).abs() < 1e-6);
+        assert!(dispatch(&continuous, area, InputEvent::Key(Key::Left)));
+        assert!((value.get() - 0.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn focused_stepper_is_reachable_by_navigation() {
+        let state = StepperState::default();
+        let manager = FocusManager::default();
+        let brightness = stepper(
+            &state,
+            ButtonStyle::new(BinaryColor::On, BinaryColor::Off),
+            MonoTextStyle::new(&FONT_6X12, BinaryColor::On),
+            0.5,
+            |_| {},
+        )
+        .with_focus(&manager, "brightness");
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        brightness
+            .draw_placed(
+                &mut display,
+                Rectangle::new(Point::zero(), Size::new(60, 24)),
+            )
+            .unwrap();
+        manager.navigate(Navigation::Next);
+        assert_eq!(manager.focused(), Some("brightness"));
+    }
+}
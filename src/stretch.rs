@@ -0,0 +1,257 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    pixelcolor::PixelColor,
+    primitives::{
+        Arc, Circle, Ellipse, Line, PrimitiveStyle, Rectangle, RoundedRectangle, Sector, Styled,
+        StyledDimensions, StyledDrawable, Triangle,
+    },
+    Drawable,
+};
+
+use crate::layoutable::Layoutable;
+use crate::{ComponentSize, ValueRange};
+
+///
+/// Primitive which can be resized to fill a given area
+///
+/// Round primitives keep their shape and are centered within the area.
+///
+pub trait Stretchable: Dimensions + Sized {
+    ///
+    /// Create a copy of this primitive fitted into a area
+    ///
+    /// # Arguments
+    ///
+    /// * `area`: area the bounding box of the new primitive should fill
+    ///
+    /// returns: Self
+    ///
+    fn fit(&self, area: Rectangle) -> Self;
+}
+
+impl Stretchable for Rectangle {
+    fn fit(&self, area: Rectangle) -> Self {
+        area
+    }
+}
+
+impl Stretchable for RoundedRectangle {
+    fn fit(&self, area: Rectangle) -> Self {
+        RoundedRectangle::new(area, self.corners)
+    }
+}
+
+impl Stretchable for Ellipse {
+    fn fit(&self, area: Rectangle) -> Self {
+        Ellipse::new(area.top_left, area.size)
+    }
+}
+
+impl Stretchable for Circle {
+    fn fit(&self, area: Rectangle) -> Self {
+        Circle::with_center(area.center(), diameter(area))
+    }
+}
+
+impl Stretchable for Arc {
+    fn fit(&self, area: Rectangle) -> Self {
+        Arc::with_center(
+            area.center(),
+            diameter(area),
+            self.angle_start,
+            self.angle_sweep,
+        )
+    }
+}
+
+impl Stretchable for Sector {
+    fn fit(&self, area: Rectangle) -> Self {
+        Sector::with_center(
+            area.center(),
+            diameter(area),
+            self.angle_start,
+            self.angle_sweep,
+        )
+    }
+}
+
+impl Stretchable for Line {
+    fn fit(&self, area: Rectangle) -> Self {
+        let bounding_box = self.bounding_box();
+        Line::new(
+            scale_point(self.start, bounding_box, area),
+            scale_point(self.end, bounding_box, area),
+        )
+    }
+}
+
+impl Stretchable for Triangle {
+    fn fit(&self, area: Rectangle) -> Self {
+        let bounding_box = self.bounding_box();
+        let [p1, p2, p3] = self.vertices.map(|p| scale_point(p, bounding_box, area));
+        Triangle::new(p1, p2, p3)
+    }
+}
+
+fn diameter(area: Rectangle) -> u32 {
+    area.size.width.min(area.size.height)
+}
+
+/// Map a point within a bounding box onto the same relative place within another area
+fn scale_point(point: Point, from: Rectangle, to: Rectangle) -> Point {
+    let scale = |value: i32, from_start: i32, from_length: u32, to_start: i32, to_length: u32| {
+        if from_length > 1 {
+            let fraction = (value - from_start) as f32 / (from_length - 1) as f32;
+            to_start + (fraction * to_length.saturating_sub(1) as f32).round() as i32
+        } else {
+            // a flat primitive stays in the middle
+            to_start + (to_length.saturating_sub(1) / 2) as i32
+        }
+    };
+    Point::new(
+        scale(
+            point.x,
+            from.top_left.x,
+            from.size.width,
+            to.top_left.x,
+            to.size.width,
+        ),
+        scale(
+            point.y,
+            from.top_left.y,
+            from.size.height,
+            to.top_left.y,
+            to.size.height,
+        ),
+    )
+}
+
+///
+/// Resize a styled primitive to the area it is placed onto, instead of keeping the size of its bounding box
+///
+/// The stroke keeps its width, only the shape itself is resized.
+///
+/// # Arguments
+///
+/// * `styled`: styled primitive, its size is used as preferred size
+///
+/// returns: impl Layoutable<C>+Sized
+///
+/// # Examples
+///
+/// separator line across the whole width of a screen
+/// ```
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::{Point, Primitive, Size};
+/// use embedded_graphics::primitives::{Line, PrimitiveStyle, Rectangle};
+/// use simple_layout::prelude::{stretch, Layoutable};
+/// let separator = stretch(
+///     Line::new(Point::zero(), Point::new(1, 0)).into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1)),
+/// );
+/// let mut display = MockDisplay::<BinaryColor>::new();
+/// separator
+///     .draw_placed(&mut display, Rectangle::new(Point::new(0, 1), Size::new(6, 3)))
+///     .unwrap();
+/// display.assert_pattern(&["      ", "      ", "######"]);
+/// ```
+pub fn stretch<C: PixelColor, T>(styled: Styled<T, PrimitiveStyle<C>>) -> impl Layoutable<C>
+where
+    T: Stretchable
+        + StyledDimensions<PrimitiveStyle<C>>
+        + StyledDrawable<PrimitiveStyle<C>, Color = C>,
+{
+    Stretch { styled }
+}
+
+struct Stretch<T, C: PixelColor> {
+    styled: Styled<T, PrimitiveStyle<C>>,
+}
+
+impl<T, C: PixelColor> Stretch<T, C>
+where
+    T: Stretchable + StyledDimensions<PrimitiveStyle<C>>,
+{
+    /// space the stroke takes outside the primitive on the top left and the bottom right
+    fn stroke_margins(&self) -> (Size, Size) {
+        let outer = self.styled.bounding_box();
+        let inner = self.styled.primitive.bounding_box();
+        let top_left = inner.top_left - outer.top_left;
+        let bottom_right = (outer.top_left + outer.size) - (inner.top_left + inner.size);
+        (
+            Size::new(top_left.x.max(0) as u32, top_left.y.max(0) as u32),
+            Size::new(bottom_right.x.max(0) as u32, bottom_right.y.max(0) as u32),
+        )
+    }
+}
+
+impl<T, C: PixelColor> Layoutable<C> for Stretch<T, C>
+where
+    T: Stretchable
+        + StyledDimensions<PrimitiveStyle<C>>
+        + StyledDrawable<PrimitiveStyle<C>, Color = C>,
+{
+    fn size(&self) -> ComponentSize {
+        let Size { width, height } = self.styled.bounding_box().size;
+        let (top_left, bottom_right) = self.stroke_margins();
+        let margins = top_left + bottom_right;
+        ComponentSize {
+            width: ValueRange::new(width, margins.width, u32::MAX),
+            height: ValueRange::new(height, margins.height, u32::MAX),
+        }
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let (top_left, bottom_right) = self.stroke_margins();
+        let margins = top_left + bottom_right;
+        let area = Rectangle::new(
+            position.top_left + top_left,
+            Size::new(
+                position.size.width.saturating_sub(margins.width),
+                position.size.height.saturating_sub(margins.height),
+            ),
+        );
+        Styled::new(self.styled.primitive.fit(area), self.styled.style).draw(target)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics::{
+        geometry::{Point, Size},
+        primitives::Rectangle,
+    };
+
+    use super::scale_point;
+
+    #[test]
+    fn scales_corners_onto_corners() {
+        let from = Rectangle::new(Point::new(10, 10), Size::new(5, 3));
+        let to = Rectangle::new(Point::new(0, 20), Size::new(41, 21));
+        assert_eq!(scale_point(Point::new(10, 10), from, to), Point::new(0, 20));
+        assert_eq!(
+            scale_point(Point::new(14, 12), from, to),
+            Point::new(40, 40)
+        );
+        assert_eq!(
+            scale_point(Point::new(12, 11), from, to),
+            Point::new(20, 30)
+        );
+    }
+
+    #[test]
+    fn keeps_flat_primitives_in_the_middle() {
+        let horizontal = Rectangle::new(Point::new(0, 5), Size::new(11, 1));
+        let to = Rectangle::new(Point::zero(), Size::new(21, 9));
+        assert_eq!(
+            scale_point(Point::new(10, 5), horizontal, to),
+            Point::new(20, 4)
+        );
+    }
+}
@@ -0,0 +1,682 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    mono_font::{MonoFont, MonoTextStyle},
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+    text::{
+        renderer::{CharacterStyle, TextMetrics, TextRenderer},
+        Baseline, Text,
+    },
+};
+#[cfg(feature = "log")]
+use log::warn;
+
+use crate::background::background;
+use crate::border::{bordered, Decorator, Insets, RoundedRectangleLine};
+use crate::dirty::DirtyRegion;
+use crate::draw::{draw_erased, ErasedDrawable};
+use crate::event::InputEvent;
+use crate::inspect::LayoutNode;
+use crate::layoutable::Layoutable;
+use crate::scale::scale;
+use crate::ComponentSize;
+
+///
+/// Purpose of a color or text style within a theme
+///
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Role {
+    /// regular content
+    Foreground,
+    /// area behind the content
+    Background,
+    /// highlighted content
+    Accent,
+    /// content which cannot be used at the moment
+    Disabled,
+}
+
+impl Role {
+    fn index(self) -> usize {
+        match self {
+            Role::Foreground => 0,
+            Role::Background => 1,
+            Role::Accent => 2,
+            Role::Disabled => 3,
+        }
+    }
+}
+
+///
+/// Colors, text styles and border shared by all themed elements below `with_theme`
+///
+#[derive(Clone)]
+pub struct Theme<C: PixelColor + 'static> {
+    colors: [C; 4],
+    text_styles: [Rc<dyn ThemeTextRenderer<C>>; 4],
+    border: Rc<dyn ThemeDecorator<C>>,
+}
+
+impl<C: PixelColor + 'static> Theme<C> {
+    ///
+    /// Create a theme from a palette and a font
+    ///
+    /// Every role gets a text style of the given font in the color of the role and the border is a 1 pixel line in
+    /// the foreground color.
+    ///
+    /// # Arguments
+    ///
+    /// * `foreground`: color of regular content
+    /// * `background`: color of the area behind the content
+    /// * `accent`: color of highlighted content
+    /// * `disabled`: color of content which cannot be used
+    /// * `font`: font of all text styles
+    ///
+    /// returns: Theme<C>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+    /// use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+    /// use simple_layout::prelude::{Role, Theme};
+    /// let dark = Theme::new(Rgb565::WHITE, Rgb565::BLACK, Rgb565::CYAN, Rgb565::new(12, 24, 12), &FONT_6X12);
+    /// assert_eq!(dark.color(Role::Accent), Rgb565::CYAN);
+    /// ```
+    pub fn new(
+        foreground: C,
+        background: C,
+        accent: C,
+        disabled: C,
+        font: &'static MonoFont<'static>,
+    ) -> Self {
+        let colors = [foreground, background, accent, disabled];
+        Self {
+            colors,
+            text_styles: colors.map(|color| {
+                Rc::new(MonoTextStyle::new(font, color)) as Rc<dyn ThemeTextRenderer<C>>
+            }),
+            border: Rc::new(RoundedRectangleLine::new(foreground, 1)),
+        }
+    }
+    ///
+    /// Replace the color of a role, the text style of the role is updated to the new color
+    ///
+    pub fn with_color(self, role: Role, color: C) -> Self {
+        let mut result = self;
+        result.colors[role.index()] = color;
+        result.text_styles[role.index()] = result.text_styles[role.index()].with_text_color(color);
+        result
+    }
+    ///
+    /// Replace the text style of a role, any style supporting a text color can be used, e.g. a `MonoTextStyle`
+    ///
+    pub fn with_text_style<S>(self, role: Role, text_style: S) -> Self
+    where
+        S: TextRenderer<Color = C> + CharacterStyle<Color = C> + Clone + 'static,
+    {
+        let mut result = self;
+        result.text_styles[role.index()] = Rc::new(text_style);
+        result
+    }
+    ///
+    /// Replace the border used by `themed_bordered` by any decorator
+    ///
+    pub fn with_border<D: Decorator<C> + 'static>(self, border: D) -> Self {
+        Self {
+            border: Rc::new(border),
+            ..self
+        }
+    }
+    /// Color of a role
+    pub fn color(&self, role: Role) -> C {
+        self.colors[role.index()]
+    }
+    /// Text style of a role
+    pub fn text_style(&self, role: Role) -> ThemeTextStyle<'_, C> {
+        ThemeTextStyle {
+            renderer: self.text_styles[role.index()].as_ref(),
+        }
+    }
+    /// Border drawn by `themed_bordered`
+    pub fn border(&self) -> impl Decorator<C> + '_ {
+        ThemeBorderRef {
+            decorator: self.border.as_ref(),
+        }
+    }
+    ///
+    /// Swap foreground and background color (white on black instead of black on white)
+    ///
+    /// The border is kept as it is.
+    ///
+    pub fn inverted(self) -> Self {
+        let foreground = self.color(Role::Background);
+        let background = self.color(Role::Foreground);
+        self.with_color(Role::Foreground, foreground)
+            .with_color(Role::Background, background)
+    }
+}
+
+impl<C: PixelColor + Debug + 'static> Debug for Theme<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Theme")
+            .field("colors", &self.colors)
+            .finish_non_exhaustive()
+    }
+}
+
+///
+/// Object safe part of a text style, so a theme can keep text styles of any type
+///
+trait ThemeTextRenderer<C: PixelColor> {
+    fn draw_string(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut ErasedDrawable<C>,
+    ) -> Point;
+    fn draw_whitespace(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut ErasedDrawable<C>,
+    ) -> Point;
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics;
+    fn line_height(&self) -> u32;
+    fn with_text_color(&self, color: C) -> Rc<dyn ThemeTextRenderer<C>>;
+}
+
+impl<C: PixelColor, S> ThemeTextRenderer<C> for S
+where
+    S: TextRenderer<Color = C> + CharacterStyle<Color = C> + Clone + 'static,
+{
+    fn draw_string(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut ErasedDrawable<C>,
+    ) -> Point {
+        match TextRenderer::draw_string(self, text, position, baseline, target) {
+            Ok(next) => next,
+            Err(never) => match never {},
+        }
+    }
+
+    fn draw_whitespace(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut ErasedDrawable<C>,
+    ) -> Point {
+        match TextRenderer::draw_whitespace(self, width, position, baseline, target) {
+            Ok(next) => next,
+            Err(never) => match never {},
+        }
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        TextRenderer::measure_string(self, text, position, baseline)
+    }
+
+    fn line_height(&self) -> u32 {
+        TextRenderer::line_height(self)
+    }
+
+    fn with_text_color(&self, color: C) -> Rc<dyn ThemeTextRenderer<C>> {
+        let mut style = self.clone();
+        style.set_text_color(Some(color));
+        Rc::new(style)
+    }
+}
+
+///
+/// Text style of a role within a theme, see `Theme::text_style`
+///
+#[derive(Copy, Clone)]
+pub struct ThemeTextStyle<'a, C: PixelColor> {
+    renderer: &'a dyn ThemeTextRenderer<C>,
+}
+
+impl<'a, C: PixelColor> TextRenderer for ThemeTextStyle<'a, C> {
+    type Color = C;
+
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        draw_erased(target, |target| {
+            self.renderer.draw_string(text, position, baseline, target)
+        })
+    }
+
+    fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        draw_erased(target, |target| {
+            self.renderer
+                .draw_whitespace(width, position, baseline, target)
+        })
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        self.renderer.measure_string(text, position, baseline)
+    }
+
+    fn line_height(&self) -> u32 {
+        self.renderer.line_height()
+    }
+}
+
+///
+/// Object safe part of a decorator, so a theme can keep a border of any type
+///
+trait ThemeDecorator<C: PixelColor> {
+    fn border_insets(&self) -> Insets;
+    fn border_fill_area(&self, position: Rectangle) -> Rectangle;
+    fn border_minimum_size(&self) -> Size;
+    fn draw_border(&self, target: &mut ErasedDrawable<C>, position: Rectangle);
+}
+
+impl<C: PixelColor, D: Decorator<C>> ThemeDecorator<C> for D {
+    fn border_insets(&self) -> Insets {
+        Decorator::insets(self)
+    }
+
+    fn border_fill_area(&self, position: Rectangle) -> Rectangle {
+        Decorator::fill_area(self, position)
+    }
+
+    fn border_minimum_size(&self) -> Size {
+        Decorator::minimum_size(self)
+    }
+
+    fn draw_border(&self, target: &mut ErasedDrawable<C>, position: Rectangle) {
+        if let Err(never) = Decorator::draw_placed(self, target, position) {
+            match never {}
+        }
+    }
+}
+
+struct ThemeBorderRef<'a, C: PixelColor> {
+    decorator: &'a dyn ThemeDecorator<C>,
+}
+
+impl<'a, C: PixelColor> Decorator<C> for ThemeBorderRef<'a, C> {
+    fn insets(&self) -> Insets {
+        self.decorator.border_insets()
+    }
+
+    fn fill_area(&self, position: Rectangle) -> Rectangle {
+        self.decorator.border_fill_area(position)
+    }
+
+    fn minimum_size(&self) -> Size {
+        self.decorator.border_minimum_size()
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        draw_erased(target, |target| {
+            self.decorator.draw_border(target, position)
+        })
+    }
+}
+
+thread_local! {
+    static THEMES: RefCell<Vec<Rc<dyn Any>>> = const { RefCell::new(Vec::new()) };
+    static DEFAULT_THEMES: RefCell<Vec<Rc<dyn Any>>> = const { RefCell::new(Vec::new()) };
+}
+
+///
+/// Access the innermost theme of the given color type
+///
+/// Falls back to the theme set by `set_default_theme` if no `with_theme` is in scope.
+///
+/// returns: None if neither a `with_theme` of this color type is drawn at the moment nor a default theme is set
+///
+pub fn current_theme<C: PixelColor + 'static>() -> Option<Rc<Theme<C>>> {
+    let find = |themes: &RefCell<Vec<Rc<dyn Any>>>| {
+        themes
+            .borrow()
+            .iter()
+            .rev()
+            .find_map(|theme| theme.clone().downcast::<Theme<C>>().ok())
+    };
+    THEMES.with(find).or_else(|| DEFAULT_THEMES.with(find))
+}
+
+///
+/// Set the theme used by themed elements which are not placed below a `with_theme`
+///
+/// The default theme is kept per thread and per color type, setting it again replaces the previous one.
+/// Without a default theme such elements have no size, draw nothing and log a warning with the `log` feature.
+///
+/// # Arguments
+///
+/// * `theme`: theme to fall back to
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use simple_layout::prelude::{set_default_theme, themed_text, Layoutable, Role, Theme};
+/// let label = themed_text(Role::Foreground, "Settings");
+/// // without a theme in scope themed elements have no size and draw nothing
+/// assert_eq!(Layoutable::<BinaryColor>::size(&label).preferred_size().height, 0);
+/// set_default_theme(Theme::new(BinaryColor::On, BinaryColor::Off, BinaryColor::On, BinaryColor::On, &FONT_6X12));
+/// assert_eq!(Layoutable::<BinaryColor>::size(&label).preferred_size().height, 12);
+/// ```
+pub fn set_default_theme<C: PixelColor + 'static>(theme: Theme<C>) {
+    DEFAULT_THEMES.with(|themes| {
+        let mut themes = themes.borrow_mut();
+        themes.retain(|theme| !theme.is::<Theme<C>>());
+        themes.push(Rc::new(theme));
+    })
+}
+
+fn with_current_theme<C: PixelColor + 'static, R>(default: R, f: impl FnOnce(&Theme<C>) -> R) -> R {
+    if let Some(theme) = current_theme::<C>() {
+        f(&theme)
+    } else {
+        #[cfg(feature = "log")]
+        warn!("Themed element used outside of with_theme and without a default theme");
+        default
+    }
+}
+
+struct ThemeScope;
+
+impl ThemeScope {
+    fn enter(theme: Rc<dyn Any>) -> Self {
+        THEMES.with(|themes| themes.borrow_mut().push(theme));
+        ThemeScope
+    }
+}
+
+impl Drop for ThemeScope {
+    fn drop(&mut self) {
+        THEMES.with(|themes| themes.borrow_mut().pop());
+    }
+}
+
+///
+/// Define the theme of all themed elements within a subtree
+///
+/// Themes can be nested, the innermost theme wins.
+///
+/// # Arguments
+///
+/// * `theme`: Theme to use
+/// * `layoutable`: root of the subtree
+///
+/// returns: impl Layoutable<C>+Sized
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use simple_layout::prelude::{
+///     expand, themed_background, themed_bordered, themed_scale, themed_text, vertical_layout,
+///     with_theme, Layoutable, Role, Theme,
+/// };
+/// let theme = Theme::new(BinaryColor::On, BinaryColor::Off, BinaryColor::On, BinaryColor::On, &FONT_6X12);
+/// let screen = with_theme(
+///     theme.clone(),
+///     vertical_layout(
+///         // the header is drawn inverted
+///         with_theme(theme.inverted(), themed_background(Role::Background, expand(themed_text(Role::Foreground, "Settings")))),
+///         0,
+///     )
+///     .append(themed_bordered(themed_scale(Role::Accent, 0.4)), 0),
+/// );
+/// assert_eq!(screen.size().preferred_size().height, 18);
+/// ```
+pub fn with_theme<L: Layoutable<C>, C: PixelColor + 'static>(
+    theme: Theme<C>,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    WithTheme {
+        theme: Rc::new(theme),
+        layoutable,
+    }
+}
+
+struct WithTheme<L: Layoutable<C>, C: PixelColor + 'static> {
+    theme: Rc<Theme<C>>,
+    layoutable: L,
+}
+
+impl<L: Layoutable<C>, C: PixelColor + 'static> Layoutable<C> for WithTheme<L, C> {
+    fn size(&self) -> ComponentSize {
+        let _scope = ThemeScope::enter(self.theme.clone());
+        self.layoutable.size()
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        let _scope = ThemeScope::enter(self.theme.clone());
+        self.layoutable.draw_placed(target, position)
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        let _scope = ThemeScope::enter(self.theme.clone());
+        children.push(LayoutNode::new(&self.layoutable, position));
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        let _scope = ThemeScope::enter(self.theme.clone());
+        self.layoutable.collect_dirty(position, dirty)
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        let _scope = ThemeScope::enter(self.theme.clone());
+        self.layoutable.handle_event(position, event)
+    }
+}
+
+///
+/// Text in the style of a role of the current theme
+///
+/// # Arguments
+///
+/// * `role`: role of the text
+/// * `text`: String to render
+///
+/// returns: impl Layoutable<C>+Sized
+///
+pub fn themed_text<C: PixelColor + 'static, StrValue: Into<Box<str>>>(
+    role: Role,
+    text: StrValue,
+) -> impl Layoutable<C> {
+    ThemedText {
+        role,
+        text: text.into(),
+        p: PhantomData,
+    }
+}
+
+struct ThemedText<C: PixelColor + 'static> {
+    role: Role,
+    text: Box<str>,
+    p: PhantomData<C>,
+}
+
+impl<C: PixelColor + 'static> Layoutable<C> for ThemedText<C> {
+    fn size(&self) -> ComponentSize {
+        with_current_theme(ComponentSize::default(), |theme: &Theme<C>| {
+            Text::new(&self.text, Point::zero(), theme.text_style(self.role)).size()
+        })
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        with_current_theme(Ok(()), |theme: &Theme<C>| {
+            Text::new(&self.text, Point::zero(), theme.text_style(self.role))
+                .draw_placed(target, position)
+        })
+    }
+}
+
+///
+/// Scale drawn in the color of a role of the current theme
+///
+/// # Arguments
+///
+/// * `role`: role of the scale
+/// * `value`: value to be represented on the scale (0.0-1.0)
+///
+/// returns: impl Layoutable<C>+Sized
+///
+pub fn themed_scale<C: PixelColor + 'static>(role: Role, value: f32) -> impl Layoutable<C> {
+    ThemedScale {
+        role,
+        value,
+        p: PhantomData,
+    }
+}
+
+struct ThemedScale<C: PixelColor + 'static> {
+    role: Role,
+    value: f32,
+    p: PhantomData<C>,
+}
+
+impl<C: PixelColor + 'static> Layoutable<C> for ThemedScale<C> {
+    fn size(&self) -> ComponentSize {
+        with_current_theme(ComponentSize::default(), |theme: &Theme<C>| {
+            scale(self.value, theme.color(self.role)).size()
+        })
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        with_current_theme(Ok(()), |theme: &Theme<C>| {
+            scale(self.value, theme.color(self.role)).draw_placed(target, position)
+        })
+    }
+}
+
+///
+/// Draw the border of the current theme around a element
+///
+/// # Arguments
+///
+/// * `layoutable`: element within the border
+///
+/// returns: impl Layoutable<C>+Sized
+///
+pub fn themed_bordered<L: Layoutable<C>, C: PixelColor + 'static>(
+    layoutable: L,
+) -> impl Layoutable<C> {
+    bordered(layoutable, ThemeBorder(PhantomData))
+}
+
+struct ThemeBorder<C: PixelColor + 'static>(PhantomData<C>);
+
+impl<C: PixelColor + 'static> Decorator<C> for ThemeBorder<C> {
+    fn insets(&self) -> Insets {
+        with_current_theme(Insets::default(), |theme: &Theme<C>| {
+            theme.border().insets()
+        })
+    }
+
+    fn fill_area(&self, position: Rectangle) -> Rectangle {
+        with_current_theme(position, |theme: &Theme<C>| {
+            theme.border().fill_area(position)
+        })
+    }
+
+    fn minimum_size(&self) -> Size {
+        with_current_theme(Size::zero(), |theme: &Theme<C>| {
+            theme.border().minimum_size()
+        })
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        with_current_theme(Ok(()), |theme: &Theme<C>| {
+            theme.border().draw_placed(target, position)
+        })
+    }
+}
+
+///
+/// Fill the area of a element with the color of a role of the current theme
+///
+/// # Arguments
+///
+/// * `role`: role of the fill color, usually `Role::Background`
+/// * `layoutable`: element to draw onto the filled area
+///
+/// returns: impl Layoutable<C>+Sized
+///
+pub fn themed_background<L: Layoutable<C>, C: PixelColor + 'static>(
+    role: Role,
+    layoutable: L,
+) -> impl Layoutable<C> {
+    background(
+        ThemePaint {
+            role,
+            p: PhantomData,
+        },
+        layoutable,
+    )
+}
+
+struct ThemePaint<C: PixelColor + 'static> {
+    role: Role,
+    p: PhantomData<C>,
+}
+
+impl<C: PixelColor + 'static> crate::background::Paint<C> for ThemePaint<C> {
+    fn fill<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        area: Rectangle,
+    ) -> Result<(), DrawError> {
+        with_current_theme(Ok(()), |theme: &Theme<C>| {
+            target.fill_solid(&area, theme.color(self.role))
+        })
+    }
+}
@@ -0,0 +1,465 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{Point, Size},
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+};
+
+use crate::{
+    dirty::DirtyRegion,
+    draw::{TransformDrawable, ZoomDrawable},
+    event::InputEvent,
+    inspect::LayoutNode,
+    layoutable::Layoutable,
+    ComponentSize,
+};
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub(crate) enum Transform {
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    MirrorHorizontal,
+    MirrorVertical,
+}
+
+impl Transform {
+    fn swaps_axes(self) -> bool {
+        matches!(self, Transform::Rotate90 | Transform::Rotate270)
+    }
+    pub(crate) fn transform_size(self, size: Size) -> Size {
+        if self.swaps_axes() {
+            Size::new(size.height, size.width)
+        } else {
+            size
+        }
+    }
+    ///
+    /// Map a point of the untransformed child onto the target
+    ///
+    /// # Arguments
+    ///
+    /// * `point`: point relative to the top left corner of the child
+    /// * `position`: place of the transformed element on the target
+    ///
+    pub(crate) fn transform_point(self, Point { x, y }: Point, position: Rectangle) -> Point {
+        let Rectangle {
+            top_left,
+            size: Size { width, height },
+        } = position;
+        let right = width as i32 - 1;
+        let bottom = height as i32 - 1;
+        top_left
+            + match self {
+                Transform::Rotate90 => Point::new(right - y, x),
+                Transform::Rotate180 => Point::new(right - x, bottom - y),
+                Transform::Rotate270 => Point::new(y, bottom - x),
+                Transform::MirrorHorizontal => Point::new(right - x, y),
+                Transform::MirrorVertical => Point::new(x, bottom - y),
+            }
+    }
+    ///
+    /// Map a area of the untransformed child onto the target
+    ///
+    pub(crate) fn transform_rectangle(self, area: Rectangle, position: Rectangle) -> Rectangle {
+        match area.bottom_right() {
+            Some(bottom_right) => Rectangle::with_corners(
+                self.transform_point(area.top_left, position),
+                self.transform_point(bottom_right, position),
+            ),
+            None => Rectangle::new(self.transform_point(area.top_left, position), Size::zero()),
+        }
+    }
+    ///
+    /// Map a point of the target back onto the untransformed child
+    ///
+    pub(crate) fn inverse_point(self, point: Point, position: Rectangle) -> Point {
+        let Rectangle {
+            top_left,
+            size: Size { width, height },
+        } = position;
+        let Point { x, y } = point - top_left;
+        let right = width as i32 - 1;
+        let bottom = height as i32 - 1;
+        match self {
+            Transform::Rotate90 => Point::new(y, right - x),
+            Transform::Rotate180 => Point::new(right - x, bottom - y),
+            Transform::Rotate270 => Point::new(bottom - y, x),
+            Transform::MirrorHorizontal => Point::new(right - x, y),
+            Transform::MirrorVertical => Point::new(x, bottom - y),
+        }
+    }
+}
+
+thread_local! {
+    /// mappings of the transformed elements which are drawn at the moment, the innermost last
+    static TARGET_MAPPINGS: RefCell<Vec<TargetMapping>> = const { RefCell::new(Vec::new()) };
+}
+
+#[derive(Copy, Clone, Debug)]
+enum TargetMapping {
+    Transform(Transform, Rectangle),
+    Zoom(u32, Rectangle),
+}
+
+impl TargetMapping {
+    fn apply(self, area: Rectangle) -> Rectangle {
+        match self {
+            TargetMapping::Transform(transform, position) => {
+                transform.transform_rectangle(area, position)
+            }
+            TargetMapping::Zoom(factor, position) => zoom_area(factor, area, position),
+        }
+    }
+    /// run a drawing of the child while its coordinates are mapped by this
+    fn during<R>(self, draw: impl FnOnce() -> R) -> R {
+        TARGET_MAPPINGS.with_borrow_mut(|mappings| mappings.push(self));
+        let result = draw();
+        TARGET_MAPPINGS.with_borrow_mut(|mappings| mappings.pop());
+        result
+    }
+}
+
+///
+/// Map a area of the element which is drawn at the moment onto the real target
+///
+/// Elements inside of rotated, mirrored or zoomed elements are drawn in a virtual coordinate system, this reverts all
+/// transformations around them. Outside of `draw_placed` the area is returned unchanged.
+///
+pub(crate) fn area_on_target(area: Rectangle) -> Rectangle {
+    TARGET_MAPPINGS.with_borrow(|mappings| {
+        mappings
+            .iter()
+            .rev()
+            .fold(area, |area, mapping| mapping.apply(area))
+    })
+}
+
+///
+/// Rotate a element by 90° clockwise
+///
+/// Width and height of the element are swapped, so a horizontal text runs from the top to the bottom.
+///
+/// # Arguments
+///
+/// * `layoutable`: element to be rotated
+///
+/// returns: impl Layoutable<C>+Sized
+///
+/// # Examples
+///
+/// vertical label along a bar
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use simple_layout::prelude::{owned_text, rotate90, Layoutable};
+/// let label = rotate90(owned_text("Level", MonoTextStyle::new(&FONT_6X12, BinaryColor::On)));
+/// assert_eq!(label.size().preferred_size().width, 12);
+/// assert_eq!(label.size().preferred_size().height, 29);
+/// ```
+/// rotate the whole screen of a device mounted in portrait orientation
+/// ```
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::Dimensions;
+/// use simple_layout::prelude::{inspect, owned_text, rotate270, vertical_layout, Layoutable};
+/// let screen = rotate270(
+///     vertical_layout(owned_text("Up", MonoTextStyle::new(&FONT_6X12, BinaryColor::On)), 0)
+///         .append(owned_text("Down", MonoTextStyle::new(&FONT_6X12, BinaryColor::On)), 0),
+/// );
+/// let mut display = MockDisplay::<BinaryColor>::new();
+/// let area = display.bounding_box();
+/// screen.draw_placed(&mut display, area).unwrap();
+/// // the places of the children are reported on the display, the first line ends up on the left side
+/// let tree = inspect(&screen, area);
+/// let layout = &tree.children()[0];
+/// let up = layout.children()[0].position();
+/// assert_eq!(up.top_left.x, 0);
+/// assert_eq!(up.size.width, 12);
+/// assert!(area.contains(up.top_left));
+/// ```
+pub fn rotate90<L: Layoutable<C>, C: PixelColor>(layoutable: L) -> impl Layoutable<C> {
+    Transformed::new(Transform::Rotate90, layoutable)
+}
+/// Rotate a element by 180° (upside down)
+pub fn rotate180<L: Layoutable<C>, C: PixelColor>(layoutable: L) -> impl Layoutable<C> {
+    Transformed::new(Transform::Rotate180, layoutable)
+}
+/// Rotate a element by 270° clockwise (90° counterclockwise), width and height are swapped
+pub fn rotate270<L: Layoutable<C>, C: PixelColor>(layoutable: L) -> impl Layoutable<C> {
+    Transformed::new(Transform::Rotate270, layoutable)
+}
+/// Mirror a element horizontally (left and right side are swapped)
+pub fn mirror_h<L: Layoutable<C>, C: PixelColor>(layoutable: L) -> impl Layoutable<C> {
+    Transformed::new(Transform::MirrorHorizontal, layoutable)
+}
+/// Mirror a element vertically (top and bottom are swapped)
+pub fn mirror_v<L: Layoutable<C>, C: PixelColor>(layoutable: L) -> impl Layoutable<C> {
+    Transformed::new(Transform::MirrorVertical, layoutable)
+}
+
+struct Transformed<L: Layoutable<C>, C: PixelColor> {
+    transform: Transform,
+    layoutable: L,
+    p: PhantomData<C>,
+}
+
+impl<L: Layoutable<C>, C: PixelColor> Transformed<L, C> {
+    fn new(transform: Transform, layoutable: L) -> Self {
+        Self {
+            transform,
+            layoutable,
+            p: PhantomData,
+        }
+    }
+    fn child_position(&self, position: Rectangle) -> Rectangle {
+        Rectangle::new(Point::zero(), self.transform.transform_size(position.size))
+    }
+}
+
+impl<L: Layoutable<C>, C: PixelColor> Layoutable<C> for Transformed<L, C> {
+    fn size(&self) -> ComponentSize {
+        let ComponentSize { width, height } = self.layoutable.size();
+        if self.transform.swaps_axes() {
+            ComponentSize {
+                width: height,
+                height: width,
+            }
+        } else {
+            ComponentSize { width, height }
+        }
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        TargetMapping::Transform(self.transform, position).during(|| {
+            self.layoutable.draw_placed(
+                &mut TransformDrawable::new(
+                    &mut target.clipped(&position),
+                    self.transform,
+                    position,
+                ),
+                self.child_position(position),
+            )
+        })
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        // the child lives in its own untransformed coordinate system, report its places on the target
+        children.push(
+            LayoutNode::new(&self.layoutable, self.child_position(position))
+                .map_positions(&|area| self.transform.transform_rectangle(area, position)),
+        );
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        let mut child_dirty = DirtyRegion::default();
+        self.layoutable
+            .collect_dirty(self.child_position(position), &mut child_dirty);
+        for area in child_dirty.rectangles() {
+            if !area.is_zero_sized() {
+                dirty.add(self.transform.transform_rectangle(*area, position));
+            }
+        }
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        self.layoutable.handle_event(
+            self.child_position(position),
+            event.map_point(|point| self.transform.inverse_point(point, position)),
+        )
+    }
+}
+
+///
+/// Draw a element enlarged by an integer factor, every pixel becomes a block of factor×factor pixels
+///
+/// The element is placed in a virtual space of the available size divided by the factor, a remainder on the right
+/// and bottom side stays empty.
+///
+/// # Arguments
+///
+/// * `factor`: enlargement (values below 1 are treated as 1)
+/// * `layoutable`: element to be enlarged
+///
+/// returns: impl Layoutable<C>+Sized
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X12;
+/// use embedded_graphics::mono_font::MonoTextStyle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use simple_layout::prelude::{owned_text, zoom, Layoutable};
+/// let big_label = zoom(3, owned_text("21°C", MonoTextStyle::new(&FONT_6X12, BinaryColor::On)));
+/// assert_eq!(big_label.size().preferred_size().height, 36);
+/// ```
+pub fn zoom<L: Layoutable<C>, C: PixelColor>(factor: u32, layoutable: L) -> impl Layoutable<C> {
+    Zoomed {
+        factor: factor.max(1),
+        layoutable,
+        p: PhantomData,
+    }
+}
+
+struct Zoomed<L: Layoutable<C>, C: PixelColor> {
+    factor: u32,
+    layoutable: L,
+    p: PhantomData<C>,
+}
+
+impl<L: Layoutable<C>, C: PixelColor> Zoomed<L, C> {
+    fn child_position(&self, position: Rectangle) -> Rectangle {
+        Rectangle::new(Point::zero(), position.size / self.factor)
+    }
+}
+
+/// place of a area of the virtual space of a zoomed element on the target
+fn zoom_area(factor: u32, area: Rectangle, position: Rectangle) -> Rectangle {
+    Rectangle::new(
+        position.top_left + area.top_left * factor as i32,
+        area.size * factor,
+    )
+}
+
+impl<L: Layoutable<C>, C: PixelColor> Layoutable<C> for Zoomed<L, C> {
+    fn size(&self) -> ComponentSize {
+        self.layoutable.size() * self.factor
+    }
+
+    fn draw_placed<DrawError>(
+        &self,
+        target: &mut impl DrawTarget<Color = C, Error = DrawError>,
+        position: Rectangle,
+    ) -> Result<(), DrawError> {
+        TargetMapping::Zoom(self.factor, position).during(|| {
+            self.layoutable.draw_placed(
+                &mut ZoomDrawable::new(&mut target.clipped(&position), self.factor, position),
+                self.child_position(position),
+            )
+        })
+    }
+
+    fn inspect_children(&self, position: Rectangle, children: &mut Vec<LayoutNode>) {
+        // the child lives in the virtual space before enlargement, report its places on the target
+        children.push(
+            LayoutNode::new(&self.layoutable, self.child_position(position))
+                .map_positions(&|area| zoom_area(self.factor, area, position)),
+        );
+    }
+
+    fn collect_dirty(&self, position: Rectangle, dirty: &mut DirtyRegion) {
+        let mut child_dirty = DirtyRegion::default();
+        self.layoutable
+            .collect_dirty(self.child_position(position), &mut child_dirty);
+        for area in child_dirty.rectangles() {
+            dirty.add(zoom_area(self.factor, *area, position));
+        }
+    }
+
+    fn handle_event(&self, position: Rectangle, event: InputEvent) -> bool {
+        let factor = self.factor as i32;
+        self.layoutable.handle_event(
+            self.child_position(position),
+            event.map_point(|point| {
+                let Point { x, y } = point - position.top_left;
+                Point::new(x.div_euclid(factor), y.div_euclid(factor))
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics::{
+        geometry::{Point, Size},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::Rectangle,
+    };
+
+    use super::{area_on_target, rotate90, zoom, Transform};
+    use crate::{
+        inspect::{inspect, LayoutNode},
+        layoutable::Layoutable,
+        placement::callback_placement,
+        scale::scale,
+    };
+
+    const ALL: [Transform; 5] = [
+        Transform::Rotate90,
+        Transform::Rotate180,
+        Transform::Rotate270,
+        Transform::MirrorHorizontal,
+        Transform::MirrorVertical,
+    ];
+
+    #[test]
+    fn inverse_point_reverts_transform_point() {
+        let position = Rectangle::new(Point::new(5, 7), Size::new(6, 4));
+        for transform in ALL {
+            let child = Rectangle::new(Point::zero(), transform.transform_size(position.size));
+            for y in 0..child.size.height as i32 {
+                for x in 0..child.size.width as i32 {
+                    let point = Point::new(x, y);
+                    let mapped = transform.transform_point(point, position);
+                    assert!(position.contains(mapped), "{transform:?} {point:?}");
+                    assert_eq!(transform.inverse_point(mapped, position), point);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rotates_rectangles() {
+        let position = Rectangle::new(Point::new(10, 0), Size::new(4, 8));
+        // the top row of the child becomes the right column
+        let row = Rectangle::new(Point::zero(), Size::new(8, 1));
+        assert_eq!(
+            Transform::Rotate90.transform_rectangle(row, position),
+            Rectangle::new(Point::new(13, 0), Size::new(1, 8))
+        );
+    }
+
+    #[test]
+    fn maps_positions_of_all_children() {
+        let element = rotate90(zoom(2, scale(0.5, BinaryColor::On)));
+        let position = Rectangle::new(Point::new(3, 3), Size::new(10, 20));
+        let tree: LayoutNode = inspect(&element, position);
+        let zoomed = &tree.children()[0];
+        assert_eq!(zoomed.position(), position);
+        let scaled = &zoomed.children()[0];
+        assert_eq!(scaled.position(), position);
+    }
+
+    #[test]
+    fn reports_drawn_areas_on_the_target() {
+        let reported = std::cell::Cell::new(None);
+        let element = zoom(
+            3,
+            callback_placement(
+                |area| reported.set(Some(area_on_target(area))),
+                scale(0.5, BinaryColor::On),
+            ),
+        );
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let position = Rectangle::new(Point::new(2, 2), Size::new(31, 10));
+        element.draw_placed(&mut display, position).unwrap();
+        assert_eq!(
+            reported.get(),
+            Some(Rectangle::new(Point::new(2, 2), Size::new(30, 9)))
+        );
+        // outside of drawing nothing is mapped anymore
+        assert_eq!(area_on_target(position), position);
+    }
+}